@@ -0,0 +1,213 @@
+"""Generate ParserInfo tables from CLDR JSON data.
+
+Reads `<cldr>/cldr-dates-{full,modern}/main/<locale>/ca-gregorian.json` from a
+cldr-json checkout (by default the trimmed snapshot in `cldr/`) and writes
+`src/locale/cldr_data.rs`, which is compiled in with the `cldr` feature.
+
+    python3 build_cldr.py [path/to/cldr-json]
+"""
+
+import json
+import os
+import re
+import sys
+
+MONTH_KEYS = [str(i) for i in range(1, 13)]
+# ParserInfo weekdays start on Monday
+DAY_KEYS = ['mon', 'tue', 'wed', 'thu', 'fri', 'sat', 'sun']
+WIDTHS = ['abbreviated', 'wide']
+
+
+def main():
+    root = sys.argv[1] if len(sys.argv) > 1 else 'cldr'
+
+    locales = {}
+    for package in ['cldr-dates-modern', 'cldr-dates-full']:
+        main_dir = os.path.join(root, package, 'main')
+        if not os.path.isdir(main_dir):
+            continue
+
+        for tag in sorted(os.listdir(main_dir)):
+            path = os.path.join(main_dir, tag, 'ca-gregorian.json')
+            if os.path.exists(path):
+                with open(path, encoding='utf-8') as handle:
+                    locales[tag] = locale_tables(tag, json.load(handle))
+
+    if not locales:
+        sys.exit('No CLDR calendar data found under {}'.format(root))
+
+    with open('src/locale/cldr_data.rs', 'w', encoding='utf-8') as handle:
+        handle.write(HEADER.format(source=source_description(root)))
+        for tag in sorted(locales, key=str.lower):
+            handle.write(render_locale(tag, locales[tag]))
+        handle.write('];\n')
+
+
+def source_description(root):
+    for package in ['cldr-core', 'cldr-dates-full', 'cldr-dates-modern']:
+        path = os.path.join(root, package, 'package.json')
+        if os.path.exists(path):
+            with open(path, encoding='utf-8') as handle:
+                return 'CLDR {}'.format(json.load(handle)['version'])
+
+    return 'the CLDR snapshot in {}/'.format(root.rstrip('/'))
+
+
+def locale_tables(tag, data):
+    calendar = data['main'][tag]['dates']['calendars']['gregorian']
+
+    months = [names(calendar['months'], key) for key in MONTH_KEYS]
+    # Months written as numbers, like 9月, give a marker for month numbers
+    month_markers = sorted({marker for key in MONTH_KEYS for marker in number_markers(calendar['months'], key)})
+    weekdays = [names(calendar['days'], key) for key in DAY_KEYS]
+
+    periods = calendar.get('dayPeriods', {})
+    am, am_rest = period_names(periods, 'am')
+    pm, pm_rest = period_names(periods, 'pm')
+    jump = am_rest | pm_rest
+
+    # Common era names mark a year as Gregorian; BCE dates are not supported.
+    eras = set()
+    for kind in ['eraAbbr', 'eraNames']:
+        for key, value in calendar.get('eras', {}).get(kind, {}).items():
+            if key == '1' and pieces(value):
+                eras.add(''.join(pieces(value)))
+
+    short = calendar.get('dateFormats', {}).get('short', '')
+    if isinstance(short, dict):
+        short = short.get('_value', '')
+    dayfirst, yearfirst = date_order(short)
+
+    return {
+        'months': months,
+        'weekdays': weekdays,
+        'month_markers': month_markers,
+        'am': am,
+        'pm': pm,
+        'jump': sorted(jump),
+        'eras': sorted(eras),
+        'dayfirst': dayfirst,
+        'yearfirst': yearfirst,
+    }
+
+
+def pieces(name):
+    """Alphabetic runs of a name, the way the tokenizer splits it."""
+    return [p.lower() for p in re.findall(r'[^\W\d_]+', name)]
+
+
+def names(context_table, key):
+    """Month or weekday names usable as a single token, from both the format
+    and stand-alone contexts. Names with spaces or digits cannot match a
+    single token, and one-letter names are too ambiguous to be useful."""
+    found = []
+    for context in ['format', 'stand-alone']:
+        for width in WIDTHS:
+            value = context_table.get(context, {}).get(width, {}).get(key)
+            if value is None:
+                continue
+
+            parts = pieces(value)
+            if len(parts) != 1 or len(parts[0]) < 2 or re.search(r'\d', value):
+                continue
+            if parts[0] not in found:
+                found.append(parts[0])
+
+    return found
+
+
+def number_markers(context_table, key):
+    """What follows the month number in names like 9月."""
+    found = []
+    for context in ['format', 'stand-alone']:
+        for width in WIDTHS:
+            value = context_table.get(context, {}).get(width, {}).get(key, '')
+            match = re.fullmatch(r'(\d+)([^\W\d_]+)', value)
+            if match and int(match.group(1)) == int(key):
+                found.append(match.group(2).lower())
+
+    return found
+
+
+def period_names(periods, key):
+    found = []
+    rest = set()
+    for width in WIDTHS + ['narrow']:
+        value = periods.get('format', {}).get(width, {}).get(key)
+        if value is None:
+            continue
+
+        # "a. m." is tokenized as "a", ".", "m", "."; the first piece
+        # identifies the period and the rest become jump words.
+        parts = pieces(value)
+        if parts and parts[0] not in found:
+            found.append(parts[0])
+        rest.update(parts[1:])
+
+    return found, rest
+
+
+def date_order(pattern):
+    # Drop quoted literals, then look at the order of the y/M/d fields
+    pattern = re.sub(r"'[^']*'", '', pattern)
+    positions = {}
+    for field in ['y', 'M', 'd']:
+        index = pattern.find(field)
+        if index >= 0:
+            positions[field] = index
+
+    if len(positions) != 3:
+        return False, False
+
+    yearfirst = positions['y'] < positions['M'] and positions['y'] < positions['d']
+    dayfirst = positions['d'] < positions['M']
+    return dayfirst, yearfirst
+
+
+def rust_list(values):
+    return '&[{}]'.format(', '.join(json.dumps(v, ensure_ascii=False) for v in values))
+
+
+def render_locale(tag, tables):
+    return LOCALE_TEMPLATE.format(
+        tag=json.dumps(tag),
+        months=',\n'.join('            ' + rust_list(m) for m in tables['months']),
+        weekdays=',\n'.join('            ' + rust_list(w) for w in tables['weekdays']),
+        month_markers=rust_list(tables['month_markers']),
+        am=rust_list(tables['am']),
+        pm=rust_list(tables['pm']),
+        jump=rust_list(tables['jump']),
+        eras=rust_list(tables['eras']),
+        dayfirst='true' if tables['dayfirst'] else 'false',
+        yearfirst='true' if tables['yearfirst'] else 'false',
+    )
+
+
+HEADER = '''// Generated by build_cldr.py from {source}. Do not edit by hand.
+
+use locale::cldr::CldrLocale;
+
+pub static LOCALES: &[CldrLocale] = &[
+'''
+
+LOCALE_TEMPLATE = '''    CldrLocale {{
+        tag: {tag},
+        months: [
+{months},
+        ],
+        weekdays: [
+{weekdays},
+        ],
+        month_markers: {month_markers},
+        am: {am},
+        pm: {pm},
+        jump: {jump},
+        eras: {eras},
+        dayfirst: {dayfirst},
+        yearfirst: {yearfirst},
+    }},
+'''
+
+
+if __name__ == '__main__':
+    main()
@@ -175,6 +175,7 @@ struct PyDateTime {
     tzo: Option<i32>
 }
 
+#[allow(clippy::too_many_arguments)]
 fn parse_and_assert(
     pdt: PyDateTime,
     info: ParserInfo,
@@ -197,7 +198,7 @@ fn parse_and_assert(
         fuzzy_with_tokens,
         default,
         ignoretz,
-        tzinfos).expect(&format!("Unable to parse date in Rust '{}'", s));
+        tzinfos).unwrap_or_else(|_| panic!("Unable to parse date in Rust '{}'", s));
 
     assert_eq!(pdt.year, rs_parsed.0.year(), "Year mismatch for '{}'", s);
     assert_eq!(pdt.month, rs_parsed.0.month(), "Month mismatch for '{}'", s);
@@ -205,7 +206,7 @@ fn parse_and_assert(
     assert_eq!(pdt.hour, rs_parsed.0.hour(), "Hour mismatch for '{}'", s);
     assert_eq!(pdt.minute, rs_parsed.0.minute(), "Minute mismatch f'or' {}", s);
     assert_eq!(pdt.second, rs_parsed.0.second(), "Second mismatch for '{}'", s);
-    assert_eq!(pdt.micros, rs_parsed.0.timestamp_subsec_micros(), "Microsecond mismatch for {}", s);
+    assert_eq!(pdt.micros, rs_parsed.0.nanosecond() / 1000, "Microsecond mismatch for {}", s);
     assert_eq!(pdt.tzo, rs_parsed.1.map(|u| u.local_minus_utc()), "Timezone Offset mismatch for {}", s);
 }
 
@@ -213,14 +214,14 @@ fn parse_and_assert_simple(
     pdt: PyDateTime,
     s: &str,
 ) {
-    let rs_parsed = dtparse::parse(s).expect(&format!("Unable to parse date in Rust '{}'", s));
+    let rs_parsed = dtparse::parse(s).unwrap_or_else(|_| panic!("Unable to parse date in Rust '{}'", s));
     assert_eq!(pdt.year, rs_parsed.0.year(), "Year mismatch for {}", s);
     assert_eq!(pdt.month, rs_parsed.0.month(), "Month mismatch for {}", s);
     assert_eq!(pdt.day, rs_parsed.0.day(), "Day mismatch for {}", s);
     assert_eq!(pdt.hour, rs_parsed.0.hour(), "Hour mismatch for {}", s);
     assert_eq!(pdt.minute, rs_parsed.0.minute(), "Minute mismatch for {}", s);
     assert_eq!(pdt.second, rs_parsed.0.second(), "Second mismatch for {}", s);
-    assert_eq!(pdt.micros, rs_parsed.0.timestamp_subsec_micros(), "Microsecond mismatch for {}", s);
+    assert_eq!(pdt.micros, rs_parsed.0.nanosecond() / 1000, "Microsecond mismatch for {}", s);
     assert_eq!(pdt.tzo, rs_parsed.1.map(|u| u.local_minus_utc()), "Timezone Offset mismatch for {}", s);
 }
 
@@ -236,7 +237,7 @@ TEST_PARSE_DEFAULT = '''
 #[test]
 fn test_parse_default{i}() {{
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {{
         year: {d.year}, month: {d.month}, day: {d.day},
         hour: {d.hour}, minute: {d.minute}, second: {d.second},
@@ -326,7 +327,7 @@ TEST_UNSPECIFIED_FALLBACK = '''
 #[test]
 fn test_unspecified_fallback{i}() {{
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2010, 1, 31).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2010, 1, 31).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {{
         year: {d.year}, month: {d.month}, day: {d.day},
         hour: {d.hour}, minute: {d.minute}, second: {d.second},
@@ -354,7 +355,7 @@ TEST_PARSE_DEFAULT_IGNORE = '''
 #[ignore]
 fn test_parse_default_ignore{i}() {{
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {{
         year: {d.year}, month: {d.month}, day: {d.day},
         hour: {d.hour}, minute: {d.minute}, second: {d.second},
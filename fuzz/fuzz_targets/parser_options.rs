@@ -0,0 +1,10 @@
+#![no_main]
+extern crate chrono;
+extern crate dtparse;
+#[macro_use]
+extern crate libfuzzer_sys;
+
+#[path = "../harness.rs"]
+mod harness;
+
+fuzz_target!(|data: &[u8]| harness::parser_options(data));
@@ -0,0 +1,125 @@
+#![allow(dead_code)]
+
+// Bodies of the fuzz targets. This file is also compiled into
+// tests/fuzz_regressions.rs, which replays every input saved under
+// fuzz/regressions/<target>/ through the same function.
+
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::str;
+
+use dtparse::BusinessCalendar;
+use dtparse::Calendar;
+use dtparse::HolidaySet;
+use dtparse::ParseError;
+use dtparse::Parser;
+use dtparse::ParserInfo;
+use dtparse::ParserInfoBuilder;
+
+const LOCALES: [&str; 9] = ["en", "de", "es", "fr", "it", "ja", "nl", "pt", "zh"];
+
+const CALENDARS: [Calendar; 7] = [
+    Calendar::Gregorian,
+    Calendar::Buddhist,
+    Calendar::Minguo,
+    Calendar::YearOffset(-1000),
+    Calendar::Persian,
+    Calendar::Hijri,
+    Calendar::Hebrew,
+];
+
+pub fn tokenize(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        let _ = ::dtparse::tokenize(s);
+    }
+}
+
+pub fn parse(data: &[u8]) {
+    if let Ok(s) = str::from_utf8(data) {
+        check_error(s, ::dtparse::parse(s));
+    }
+}
+
+/// `dtparse::parse` on input starting with a four-digit year, which takes
+/// the ISO 8601 fast path when it has the canonical shape, checked against
+/// the tokenizing parser. A calendar other than `Gregorian`, even one that
+/// leaves years as they are, keeps a parser off the fast path.
+pub fn iso(data: &[u8]) {
+    let s = match str::from_utf8(data) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+    let bytes = s.as_bytes();
+    if bytes.len() < 5 || !bytes[..4].iter().all(u8::is_ascii_digit) || bytes[4] != b'-' {
+        return;
+    }
+
+    let default = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let info = ParserInfoBuilder::new().calendar(Calendar::YearOffset(0)).build().unwrap();
+    let fast = Parser::default().parse(s, None, None, false, false, Some(&default), false, HashMap::new());
+    let slow = Parser::new(info).parse(s, None, None, false, false, Some(&default), false, HashMap::new());
+
+    assert_eq!(fast.as_ref().ok(), slow.as_ref().ok(), "{:?}", s);
+    check_error(s, fast);
+}
+
+/// Errors must point at a part of the input they were returned for.
+fn check_error<T>(s: &str, result: Result<T, ParseError>) {
+    if let Err(err) = result {
+        assert_eq!(err.input(), s);
+        assert_eq!(s.get(err.span()), Some(err.token()));
+        let _ = err.diagnostic();
+    }
+}
+
+/// `Parser::parse` with the options taken from the first two bytes:
+///
+/// - byte 0, bits 0-1: `dayfirst` unset, false or true; bits 2-3 likewise
+///   for `yearfirst`; bit 4 `fuzzy`; bit 5 `fuzzy_with_tokens`; bit 6
+///   `ignoretz`; bit 7 adds the US holidays and a business calendar
+/// - byte 1: the locale and calendar of the `ParserInfo`
+pub fn parser_options(data: &[u8]) {
+    if data.len() < 2 {
+        return;
+    }
+
+    let (flags, choice) = (data[0], data[1] as usize);
+    let s = match str::from_utf8(&data[2..]) {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    let option = |bits: u8| match bits & 3 {
+        0 => None,
+        1 => Some(false),
+        _ => Some(true),
+    };
+
+    let locale = ParserInfo::for_locale(LOCALES[choice % LOCALES.len()]).unwrap();
+    let info = ParserInfoBuilder::from(locale)
+        .calendar(CALENDARS[choice / LOCALES.len() % CALENDARS.len()])
+        .build()
+        .unwrap();
+
+    let mut parser = Parser::new(info);
+    if flags & 0x80 != 0 {
+        parser.add_holiday_calendar(HolidaySet::us());
+        parser.set_business_calendar(BusinessCalendar::new());
+    }
+
+    let mut tzinfos = HashMap::new();
+    tzinfos.insert("BRST".to_owned(), -10800);
+
+    let default = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let result = parser.parse(
+        s,
+        option(flags),
+        option(flags >> 2),
+        flags & 0x10 != 0,
+        flags & 0x20 != 0,
+        Some(&default),
+        flags & 0x40 != 0,
+        tzinfos,
+    );
+    check_error(s, result);
+}
@@ -0,0 +1,481 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use calendar::era_key;
+use Calendar;
+use Clock;
+use ParserInfo;
+use YearPivot;
+
+/// A category of words recognized by a `ParserInfo`, and what the value
+/// attached to each word means.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Vocabulary {
+    /// Words skipped over between date components ("at", "of", ","). Values
+    /// are ignored.
+    Jump,
+    /// Weekday names; 0 is Monday and 6 is Sunday.
+    Weekday,
+    /// Month names; 1 is January and 12 is December.
+    Month,
+    /// Time units; 0 is hours, 1 minutes and 2 seconds.
+    Hms,
+    /// Date units following a number (年, 月, 日); 0 is years, 1 months and
+    /// 2 days.
+    Ymd,
+    /// 0 for AM, 1 for PM.
+    AmPm,
+    /// Names for UTC ("UTC", "GMT", "Z"). Values are ignored.
+    UtcZone,
+    /// Words joining a day to its month ("of"). Values are ignored.
+    Pertain,
+    /// Named times of day ("noon", "evening"); the value is the hour.
+    NamedTime,
+    /// Relative days ("tomorrow"); the value is the offset in days from the
+    /// default date.
+    RelativeDay,
+}
+
+const CATEGORIES: [Vocabulary; 10] = [
+    Vocabulary::Jump,
+    Vocabulary::Weekday,
+    Vocabulary::Month,
+    Vocabulary::Hms,
+    Vocabulary::Ymd,
+    Vocabulary::AmPm,
+    Vocabulary::UtcZone,
+    Vocabulary::Pertain,
+    Vocabulary::NamedTime,
+    Vocabulary::RelativeDay,
+];
+
+/// One meaning of a word: its category and value (as passed to
+/// `ParserInfoBuilder::add`), and the index of the info it came from in
+/// the order they were merged, the starting info being 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Meaning {
+    pub category: Vocabulary,
+    pub value: i64,
+    pub source: usize,
+}
+
+/// A word that merged infos disagree on, such as "mar" (March in English,
+/// Tuesday in French). The meaning from the earlier info is kept.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    pub word: String,
+    pub kept: Meaning,
+    pub dropped: Meaning,
+}
+
+/// A word value outside the range its category allows, e.g. month 13.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidVocabulary {
+    pub category: Vocabulary,
+    pub value: i64,
+}
+
+/// Build a customised `ParserInfo`, starting from the default English
+/// vocabulary (`new`) or from an existing info (`from`).
+///
+/// ```
+/// use dtparse::ParserInfo;
+/// use dtparse::Vocabulary;
+///
+/// let info = ParserInfo::builder()
+///     .add(Vocabulary::Month, 9, &["Sept."])
+///     .add(Vocabulary::RelativeDay, -2, &["ereyesterday"])
+///     .tzoffset("EST", -5 * 3600)
+///     .dayfirst(true)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParserInfoBuilder {
+    info: ParserInfo,
+    invalid: Option<InvalidVocabulary>,
+    // Number of infos merged so far, counting the starting one
+    sources: usize,
+    // Which info each merged-in word came from; words not listed are from 0
+    origin: HashMap<String, usize>,
+    conflicts: Vec<Conflict>,
+}
+
+impl Default for ParserInfoBuilder {
+    fn default() -> Self {
+        ParserInfoBuilder::from(ParserInfo::default())
+    }
+}
+
+impl From<ParserInfo> for ParserInfoBuilder {
+    fn from(info: ParserInfo) -> Self {
+        ParserInfoBuilder {
+            info,
+            invalid: None,
+            sources: 1,
+            origin: HashMap::new(),
+            conflicts: Vec::new(),
+        }
+    }
+}
+
+impl ParserInfoBuilder {
+    pub fn new() -> Self {
+        ParserInfoBuilder::default()
+    }
+
+    /// Recognize `words` as `value` in `category`, in addition to the words
+    /// already known.
+    pub fn add(mut self, category: Vocabulary, value: i64, words: &[&str]) -> Self {
+        if category == Vocabulary::RelativeDay {
+            for word in words {
+                self.info.relative_day.insert(word.to_lowercase(), value);
+            }
+            return self;
+        }
+
+        let stored = match stored_value(category, value) {
+            Some(stored) => stored,
+            None => {
+                self.invalid = self.invalid.or(Some(InvalidVocabulary { category, value }));
+                return self;
+            }
+        };
+
+        if let Some(table) = table(&mut self.info, category) {
+            for word in words {
+                table.insert(word.to_lowercase(), stored);
+            }
+        }
+        self
+    }
+
+    /// Stop recognizing `words` in `category`.
+    pub fn remove(mut self, category: Vocabulary, words: &[&str]) -> Self {
+        for word in words {
+            let word = word.to_lowercase();
+            match table(&mut self.info, category) {
+                Some(table) => table.remove(&word),
+                None => self.info.relative_day.remove(&word).map(|_| 0),
+            };
+        }
+        self
+    }
+
+    /// Replace the words for `value` in `category` with `words`. For the
+    /// categories without values (`Jump`, `UtcZone` and `Pertain`) the whole
+    /// category is replaced.
+    pub fn replace(mut self, category: Vocabulary, value: i64, words: &[&str]) -> Self {
+        if category == Vocabulary::RelativeDay {
+            self.info.relative_day.retain(|_, v| *v != value);
+        } else if let Some(stored) = stored_value(category, value) {
+            let whole = !has_values(category);
+            if let Some(table) = table(&mut self.info, category) {
+                table.retain(|_, v| !whole && *v != stored);
+            }
+        }
+
+        self.add(category, value, words)
+    }
+
+    /// Remove every word in `category`.
+    pub fn clear(mut self, category: Vocabulary) -> Self {
+        match table(&mut self.info, category) {
+            Some(table) => table.clear(),
+            None => self.info.relative_day.clear(),
+        }
+        self
+    }
+
+    /// Recognize `name` as a time zone `seconds` east of UTC.
+    pub fn tzoffset(mut self, name: &str, seconds: i32) -> Self {
+        self.info.tzoffset.insert(name.to_lowercase(), seconds);
+        self
+    }
+
+    pub fn remove_tzoffset(mut self, name: &str) -> Self {
+        self.info.tzoffset.remove(&name.to_lowercase());
+        self
+    }
+
+    /// Recognize the era name `name`, such as "AD" or "n. Chr.", as marking
+    /// a year in `calendar`.
+    pub fn era(mut self, name: &str, calendar: Calendar) -> Self {
+        self.info.eras.insert(era_key(name), calendar);
+        self
+    }
+
+    /// Default for `dayfirst` when the caller of `parse` doesn't specify one.
+    pub fn dayfirst(mut self, dayfirst: bool) -> Self {
+        self.info.dayfirst = dayfirst;
+        self
+    }
+
+    /// Default for `yearfirst` when the caller of `parse` doesn't specify one.
+    pub fn yearfirst(mut self, yearfirst: bool) -> Self {
+        self.info.yearfirst = yearfirst;
+        self
+    }
+
+    /// Calendar years are counted in when the input doesn't name an era.
+    pub fn calendar(mut self, calendar: Calendar) -> Self {
+        self.info.calendar = calendar;
+        self
+    }
+
+    /// Accept AM/PM words before the hour as well as after it, as in
+    /// 午後3時.
+    pub fn ampm_prefix(mut self, ampm_prefix: bool) -> Self {
+        self.info.ampm_prefix = ampm_prefix;
+        self
+    }
+
+    /// Year two-digit years are resolved around, as set out by the
+    /// `year_pivot`. Defaults to the current year.
+    pub fn reference_year(mut self, year: i32) -> Self {
+        self.info.year = year;
+        self.info.century = year / 100 * 100;
+        self.info.year_overridden = true;
+        self
+    }
+
+    /// How two-digit years are expanded. Defaults to
+    /// `YearPivot::SlidingWindow`.
+    pub fn year_pivot(mut self, pivot: YearPivot) -> Self {
+        self.info.year_pivot = pivot;
+        self
+    }
+
+    /// Clock giving the default date and, unless `reference_year` is called
+    /// afterwards, the reference year. Defaults to `SystemClock`.
+    pub fn clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.info.set_clock(clock);
+        self
+    }
+
+    /// Add the vocabulary, time zone offsets and era names of `other`.
+    /// `dayfirst`, `yearfirst`, the calendar, the clock, the reference year
+    /// and the year pivot are left as they are, while `ampm_prefix` is
+    /// turned on if `other` has it.
+    ///
+    /// Earlier infos take precedence: where `other` gives a word a meaning
+    /// that collides with one the builder already has, the builder's is kept
+    /// and `other`'s is dropped and recorded in `conflicts`. Any two meanings
+    /// collide unless one of them is a jump or pertain word, as those never
+    /// stop a word from being read another way; so "of" can be a jump word
+    /// in one info and pertain in another, while "mar" stays March when
+    /// French would make it Tuesday.
+    pub fn merge(mut self, other: &ParserInfo) -> Self {
+        let source = self.sources;
+        self.sources += 1;
+
+        let known = meanings(&self.info);
+        for (word, theirs) in meanings(other) {
+            let ours = match known.get(&word) {
+                Some(ours) => ours,
+                None => {
+                    for &(category, value) in &theirs {
+                        self = self.add(category, value, &[&word]);
+                    }
+                    self.origin.insert(word, source);
+                    continue;
+                }
+            };
+
+            let kept_source = self.origin.get(&word).cloned().unwrap_or(0);
+            for &(category, value) in theirs.iter().filter(|m| !ours.contains(m)) {
+                let same = ours.iter().find(|m| m.0 == category);
+                let collides = same.or_else(|| ours.iter().find(|m| !is_connective(m.0)));
+                match collides.filter(|_| !is_connective(category)) {
+                    Some(kept) => self.conflicts.push(Conflict {
+                        word: word.clone(),
+                        kept: Meaning { category: kept.0, value: kept.1, source: kept_source },
+                        dropped: Meaning { category, value, source },
+                    }),
+                    None => self = self.add(category, value, &[&word]),
+                }
+            }
+        }
+
+        for (name, &offset) in &other.tzoffset {
+            self.info.tzoffset.entry(name.clone()).or_insert(offset);
+        }
+        for (name, &calendar) in &other.eras {
+            self.info.eras.entry(name.clone()).or_insert(calendar);
+        }
+        self.info.ampm_prefix |= other.ampm_prefix;
+        self
+    }
+
+    /// Start from the first of `infos` (or the default, if there are none)
+    /// and merge in the rest, in order.
+    pub fn compose(infos: &[ParserInfo]) -> Self {
+        let first = infos.first().cloned().unwrap_or_default();
+        infos
+            .iter()
+            .skip(1)
+            .fold(ParserInfoBuilder::from(first), |builder, info| builder.merge(info))
+    }
+
+    /// Words dropped by `merge` so far because an earlier info gave them a
+    /// different meaning.
+    pub fn conflicts(&self) -> &[Conflict] {
+        &self.conflicts
+    }
+
+    /// Finish building, failing if any word was given a value outside the
+    /// range of its category.
+    pub fn build(self) -> Result<ParserInfo, InvalidVocabulary> {
+        match self.invalid {
+            Some(invalid) => Err(invalid),
+            None => Ok(self.info.compiled()),
+        }
+    }
+}
+
+fn has_values(category: Vocabulary) -> bool {
+    !matches!(category, Vocabulary::Jump | Vocabulary::UtcZone | Vocabulary::Pertain)
+}
+
+/// How `value` is stored in the table for `category`, if it is in range.
+/// Relative days are stored as-is and aren't handled here.
+fn stored_value(category: Vocabulary, value: i64) -> Option<usize> {
+    let (low, high) = match category {
+        Vocabulary::Jump | Vocabulary::UtcZone | Vocabulary::Pertain => return Some(0),
+        Vocabulary::Weekday => (0, 6),
+        Vocabulary::Month => (1, 12),
+        Vocabulary::Hms | Vocabulary::Ymd => (0, 2),
+        Vocabulary::AmPm => (0, 1),
+        Vocabulary::NamedTime => (0, 23),
+        Vocabulary::RelativeDay => return None,
+    };
+
+    if value < low || value > high {
+        None
+    } else if category == Vocabulary::Month {
+        Some(value as usize - 1)
+    } else {
+        Some(value as usize)
+    }
+}
+
+fn table(info: &mut ParserInfo, category: Vocabulary) -> Option<&mut HashMap<String, usize>> {
+    match category {
+        Vocabulary::Jump => Some(&mut info.jump),
+        Vocabulary::Weekday => Some(&mut info.weekday),
+        Vocabulary::Month => Some(&mut info.months),
+        Vocabulary::Hms => Some(&mut info.hms),
+        Vocabulary::Ymd => Some(&mut info.ymd),
+        Vocabulary::AmPm => Some(&mut info.ampm),
+        Vocabulary::UtcZone => Some(&mut info.utczone),
+        Vocabulary::Pertain => Some(&mut info.pertain),
+        Vocabulary::NamedTime => Some(&mut info.named_time),
+        Vocabulary::RelativeDay => None,
+    }
+}
+
+/// Every word `info` knows, with its meanings as (category, value) pairs
+/// in the form `add` takes them.
+fn meanings(info: &ParserInfo) -> BTreeMap<String, Vec<(Vocabulary, i64)>> {
+    let mut meanings = BTreeMap::new();
+
+    for &category in CATEGORIES.iter() {
+        let words: Vec<(&String, i64)> = match category {
+            Vocabulary::Jump => set_words(&info.jump),
+            Vocabulary::UtcZone => set_words(&info.utczone),
+            Vocabulary::Pertain => set_words(&info.pertain),
+            Vocabulary::Weekday => info.weekday.iter().map(|(w, &v)| (w, v as i64)).collect(),
+            Vocabulary::Month => info.months.iter().map(|(w, &v)| (w, v as i64 + 1)).collect(),
+            Vocabulary::Hms => info.hms.iter().map(|(w, &v)| (w, v as i64)).collect(),
+            Vocabulary::Ymd => info.ymd.iter().map(|(w, &v)| (w, v as i64)).collect(),
+            Vocabulary::AmPm => info.ampm.iter().map(|(w, &v)| (w, v as i64)).collect(),
+            Vocabulary::NamedTime => info.named_time.iter().map(|(w, &v)| (w, v as i64)).collect(),
+            Vocabulary::RelativeDay => info.relative_day.iter().map(|(w, &v)| (w, v)).collect(),
+        };
+
+        for (word, value) in words {
+            meanings
+                .entry(word.clone())
+                .or_insert_with(Vec::new)
+                .push((category, value));
+        }
+    }
+
+    meanings
+}
+
+/// Whether meanings in `category` are compatible with any other meaning of
+/// the same word.
+fn is_connective(category: Vocabulary) -> bool {
+    category == Vocabulary::Jump || category == Vocabulary::Pertain
+}
+
+fn set_words(table: &HashMap<String, usize>) -> Vec<(&String, i64)> {
+    table.keys().map(|w| (w, 0)).collect()
+}
+
+mod test {
+
+    use builder::Conflict;
+    use builder::InvalidVocabulary;
+    use builder::Meaning;
+    use builder::ParserInfoBuilder;
+    use builder::Vocabulary;
+    use ParserInfo;
+
+    #[test]
+    fn invalid_values() {
+        let built = ParserInfoBuilder::new()
+            .add(Vocabulary::Month, 13, &["Undecimber"])
+            .add(Vocabulary::Weekday, 7, &["Caturday"])
+            .build();
+        assert_eq!(built, Err(InvalidVocabulary { category: Vocabulary::Month, value: 13 }));
+    }
+
+    #[test]
+    fn replace_and_merge() {
+        let info = ParserInfoBuilder::new()
+            .replace(Vocabulary::Month, 5, &["Mai"])
+            .replace(Vocabulary::Pertain, 0, &["de"])
+            .build()
+            .unwrap();
+        assert_eq!(info.get_month("May"), None);
+        assert_eq!(info.get_month("mai"), Some(5));
+        assert!(info.get_pertain("de") && !info.get_pertain("of"));
+
+        let merged = ParserInfoBuilder::from(info)
+            .merge(&ParserInfo::default())
+            .build()
+            .unwrap();
+        assert_eq!(merged.get_month("May"), Some(5));
+        assert!(merged.get_pertain("of"));
+        assert_eq!(ParserInfo::default().get_month("mai"), None);
+    }
+
+    #[test]
+    fn merge_conflicts() {
+        let sept = ParserInfo::builder().add(Vocabulary::Month, 7, &["Sept"]).build().unwrap();
+        let builder = ParserInfoBuilder::new()
+            .merge(&ParserInfo::for_locale("fr").unwrap())
+            .merge(&sept);
+        let info = builder.clone().build().unwrap();
+
+        assert_eq!(info.get_month("mar"), Some(3));
+        assert_eq!(info.get_weekday("mar"), None);
+        assert_eq!(info.get_weekday("mardi"), Some(1));
+        assert!(builder.conflicts().contains(&Conflict {
+            word: "mar".to_owned(),
+            kept: Meaning { category: Vocabulary::Month, value: 3, source: 0 },
+            dropped: Meaning { category: Vocabulary::Weekday, value: 1, source: 1 },
+        }));
+
+        assert_eq!(info.get_month("sept"), Some(9));
+        assert!(builder.conflicts().contains(&Conflict {
+            word: "sept".to_owned(),
+            kept: Meaning { category: Vocabulary::Month, value: 9, source: 0 },
+            dropped: Meaning { category: Vocabulary::Month, value: 7, source: 2 },
+        }));
+        // Same meaning in both isn't a conflict
+        assert!(!builder.conflicts().iter().any(|c| c.word == "the" || c.word == "mai"));
+    }
+}
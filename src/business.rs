@@ -0,0 +1,280 @@
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::NaiveDate;
+use std::collections::BTreeSet;
+
+use holiday::HolidaySet;
+use weekday::DayOfWeek;
+
+/// Which days count as business days: a weekend mask plus a set of holiday
+/// dates. The default calendar has a Saturday/Sunday weekend and no holidays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusinessCalendar {
+    // Indexed by `DayOfWeek::to_numeral`, so Sunday is 0
+    weekend: [bool; 7],
+    holidays: BTreeSet<NaiveDate>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        BusinessCalendar {
+            weekend: [true, false, false, false, false, false, true],
+            holidays: BTreeSet::new(),
+        }
+    }
+}
+
+impl BusinessCalendar {
+    pub fn new() -> Self {
+        BusinessCalendar::default()
+    }
+
+    /// Replace the weekend, e.g. Friday and Saturday for much of the Middle East.
+    pub fn set_weekend(&mut self, days: &[DayOfWeek]) -> &mut Self {
+        self.weekend = [false; 7];
+        for day in days {
+            self.weekend[day.to_numeral() as usize] = true;
+        }
+        self
+    }
+
+    pub fn add_holiday(&mut self, date: NaiveDate) -> &mut Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// Add every holiday in `set` that falls between `from_year` and
+    /// `to_year`, inclusive.
+    pub fn add_holidays(&mut self, set: &HolidaySet, from_year: i32, to_year: i32) -> &mut Self {
+        for year in from_year..to_year + 1 {
+            self.holidays.extend(set.dates(year));
+        }
+        self
+    }
+
+    pub fn is_weekend(&self, date: NaiveDate) -> bool {
+        self.weekend[date.weekday().num_days_from_sunday() as usize]
+    }
+
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays.contains(&date)
+    }
+
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date) && !self.is_holiday(date)
+    }
+
+    /// Move `days` business days forward (or backward, if negative) from
+    /// `date`. Zero days returns `date` unchanged, whether or not it is a
+    /// business day. Returns `None` if the weekend covers the whole week or
+    /// the result is out of range.
+    pub fn add_business_days(&self, date: NaiveDate, days: i64) -> Option<NaiveDate> {
+        let per_week = self.weekend.iter().filter(|&&w| !w).count() as i64;
+        if per_week == 0 {
+            return None;
+        }
+
+        let step = if days < 0 { -1 } else { 1 };
+        let mut remaining = days.checked_abs()?;
+
+        // Every business day takes up at least one calendar day
+        let room = if step > 0 {
+            NaiveDate::MAX.signed_duration_since(date)
+        } else {
+            date.signed_duration_since(NaiveDate::MIN)
+        };
+        if remaining > room.num_days() {
+            return None;
+        }
+
+        let mut current = date;
+        while remaining > 0 {
+            // Whole weeks before the next holiday each hold `per_week`
+            // business days, so skip them rather than counting day by day
+            let holiday = if step > 0 {
+                self.holidays.range(current.succ_opt()?..).next()
+            } else {
+                self.holidays.range(..current).next_back()
+            };
+            let clear = holiday.map_or(i64::MAX, |h| h.signed_duration_since(current).num_days().abs() - 1);
+            let weeks = (clear / 7).min((remaining - 1) / per_week);
+            if weeks > 0 {
+                current = current.checked_add_signed(Duration::weeks(step * weeks))?;
+                remaining -= weeks * per_week;
+            }
+
+            current = current.checked_add_signed(Duration::days(step))?;
+            if self.is_business_day(current) {
+                remaining -= 1;
+            }
+        }
+
+        Some(current)
+    }
+
+    /// First business day strictly after `date`.
+    pub fn next_business_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        self.add_business_days(date, 1)
+    }
+
+    /// Last business day strictly before `date`.
+    pub fn previous_business_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        self.add_business_days(date, -1)
+    }
+
+    /// Number of business days in the half-open range `(start, end]`;
+    /// negative if `end` is before `start`.
+    pub fn business_days_between(&self, start: NaiveDate, end: NaiveDate) -> i64 {
+        let (from, to, sign) = if start <= end {
+            (start, end, 1)
+        } else {
+            (end, start, -1)
+        };
+
+        let mut count = 0;
+        let mut current = from;
+        while current < to {
+            current = match current.checked_add_signed(Duration::days(1)) {
+                Some(next) => next,
+                None => break,
+            };
+            if self.is_business_day(current) {
+                count += 1;
+            }
+        }
+
+        sign * count
+    }
+}
+
+fn is_business_word(token: &str) -> bool {
+    let token = token.to_lowercase();
+    token == "business" || token == "working"
+}
+
+fn is_day_word(token: &str) -> bool {
+    let token = token.to_lowercase();
+    token == "day" || token == "days"
+}
+
+/// Recognize a business-day offset starting at `idx`: "T+3", "3 business
+/// days [after|from|before]", or "next/previous business day". Returns the
+/// offset and the index of the last token consumed.
+pub fn find_business_offset(tokens: &[&str], idx: usize) -> Option<(i64, usize)> {
+    // Positions of the non-whitespace tokens from `idx` onwards
+    let words: Vec<usize> = (idx..tokens.len()).filter(|&i| tokens[i] != " ").take(5).collect();
+    let word = |n: usize| words.get(n).map(|&i| tokens[i]);
+
+    if words.first() != Some(&idx) {
+        return None;
+    }
+
+    // T+3, T-1
+    if tokens[idx].to_lowercase() == "t" && idx + 2 < tokens.len()
+        && (tokens[idx + 1] == "+" || tokens[idx + 1] == "-")
+    {
+        let days = tokens[idx + 2].parse::<i64>().ok()?;
+        let sign = if tokens[idx + 1] == "-" { -1 } else { 1 };
+        return Some((sign * days, idx + 2));
+    }
+
+    let days = if let Ok(days) = tokens[idx].parse::<i64>() {
+        days
+    } else {
+        match tokens[idx].to_lowercase().as_str() {
+            "next" => 1,
+            "previous" | "prior" => -1,
+            _ => return None,
+        }
+    };
+
+    if !word(1).map_or(false, is_business_word) || !word(2).map_or(false, is_day_word) {
+        return None;
+    }
+
+    match word(3).map(|w| w.to_lowercase()) {
+        Some(ref w) if w == "after" || w == "from" => Some((days, words[3])),
+        Some(ref w) if w == "before" => Some((-days, words[3])),
+        _ => Some((days, words[2])),
+    }
+}
+
+mod test {
+
+    use business::BusinessCalendar;
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use holiday::HolidaySet;
+    use weekday::DayOfWeek;
+
+    #[test]
+    fn business_day_arithmetic() {
+        let cal = BusinessCalendar::new();
+        // Thursday
+        let date = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap();
+
+        assert!(cal.is_business_day(date));
+        assert!(!cal.is_business_day(NaiveDate::from_ymd_opt(2003, 9, 27).unwrap()));
+        assert_eq!(cal.add_business_days(date, 0), Some(date));
+        assert_eq!(cal.add_business_days(date, 3), Some(NaiveDate::from_ymd_opt(2003, 9, 30).unwrap()));
+        assert_eq!(cal.add_business_days(date, -4), Some(NaiveDate::from_ymd_opt(2003, 9, 19).unwrap()));
+        assert_eq!(cal.next_business_day(NaiveDate::from_ymd_opt(2003, 9, 26).unwrap()), Some(NaiveDate::from_ymd_opt(2003, 9, 29).unwrap()));
+        assert_eq!(cal.business_days_between(date, NaiveDate::from_ymd_opt(2003, 10, 2).unwrap()), 5);
+        assert_eq!(cal.business_days_between(NaiveDate::from_ymd_opt(2003, 10, 2).unwrap(), date), -5);
+    }
+
+    #[test]
+    fn business_day_holidays_and_weekends() {
+        let mut cal = BusinessCalendar::new();
+        cal.add_holidays(&HolidaySet::us(), 2003, 2003);
+
+        // Christmas 2003 is a Thursday
+        assert_eq!(
+            cal.add_business_days(NaiveDate::from_ymd_opt(2003, 12, 24).unwrap(), 1),
+            Some(NaiveDate::from_ymd_opt(2003, 12, 26).unwrap())
+        );
+
+        cal.set_weekend(&[DayOfWeek::Friday, DayOfWeek::Saturday]);
+        assert_eq!(
+            cal.add_business_days(NaiveDate::from_ymd_opt(2003, 9, 25).unwrap(), 1),
+            Some(NaiveDate::from_ymd_opt(2003, 9, 28).unwrap())
+        );
+
+        cal.set_weekend(&[
+            DayOfWeek::Sunday, DayOfWeek::Monday, DayOfWeek::Tuesday, DayOfWeek::Wednesday,
+            DayOfWeek::Thursday, DayOfWeek::Friday, DayOfWeek::Saturday,
+        ]);
+        assert_eq!(cal.add_business_days(NaiveDate::from_ymd_opt(2003, 9, 25).unwrap(), 1), None);
+    }
+
+    #[test]
+    fn long_business_day_offsets() {
+        let mut cal = BusinessCalendar::new();
+        cal.add_holidays(&HolidaySet::us(), 2000, 2010);
+        let date = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap();
+
+        // Counting one day at a time
+        let stepped = |cal: &BusinessCalendar, days: i64| {
+            let step = Duration::days(days.signum());
+            let mut current = date;
+            for _ in 0..days.abs() {
+                current += step;
+                while !cal.is_business_day(current) {
+                    current += step;
+                }
+            }
+            current
+        };
+
+        for &weekend in [&[DayOfWeek::Saturday, DayOfWeek::Sunday][..], &[DayOfWeek::Friday][..]].iter() {
+            cal.set_weekend(weekend);
+            for &days in [-1500, -260, -6, 5, 7, 11, 250, 1500].iter() {
+                assert_eq!(cal.add_business_days(date, days), Some(stepped(&cal, days)), "{}", days);
+            }
+        }
+
+        assert_eq!(cal.add_business_days(date, 999_999_999), None);
+        assert_eq!(cal.add_business_days(date, i64::MIN), None);
+        assert!(cal.add_business_days(date, 50_000_000).is_some());
+    }
+}
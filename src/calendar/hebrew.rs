@@ -0,0 +1,141 @@
+// Hebrew calendar, from the molad and postponement rules. Months are
+// numbered from Nisan, so the year (which changes at Rosh Hashanah) starts
+// with month 7, Tishrei; month 12 is Adar (Adar I in leap years) and month 13
+// is Adar II.
+
+use calendar::from_jdn;
+use calendar::jdn;
+use chrono::NaiveDate;
+
+pub const MONTHS: [&[&str]; 13] = [
+    &["Nisan", "ניסן"],
+    &["Iyar", "Iyyar", "אייר"],
+    &["Sivan", "סיון", "סיוון"],
+    &["Tammuz", "Tamuz", "תמוז"],
+    &["Av", "אב"],
+    &["Elul", "אלול"],
+    &["Tishrei", "Tishri", "תשרי"],
+    &["Cheshvan", "Heshvan", "Marcheshvan", "חשון", "חשוון"],
+    &["Kislev", "כסלו"],
+    &["Tevet", "Teves", "טבת"],
+    &["Shevat", "Shvat", "שבט"],
+    &["Adar", "Adar I", "Adar Aleph", "אדר", "אדר א"],
+    &["Adar II", "Adar Bet", "Adar Sheni", "אדר ב"],
+];
+
+/// Julian day number of the day before 1 Tishrei 1 AM
+const EPOCH: i64 = 347997;
+
+fn is_leap(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+fn months_in_year(year: i64) -> u32 {
+    if is_leap(year) {
+        13
+    } else {
+        12
+    }
+}
+
+/// Days from the epoch to Rosh Hashanah, before the second set of
+/// postponements.
+fn elapsed_days(year: i64) -> i64 {
+    let months = (235 * year - 234).div_euclid(19);
+    let parts = 12084 + 13753 * months;
+    let day = months * 29 + parts.div_euclid(25920);
+
+    if (3 * (day + 1)).rem_euclid(7) < 3 {
+        day + 1
+    } else {
+        day
+    }
+}
+
+fn new_year_delay(year: i64) -> i64 {
+    let last = elapsed_days(year - 1);
+    let present = elapsed_days(year);
+    let next = elapsed_days(year + 1);
+
+    if next - present == 356 {
+        2
+    } else if present - last == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Julian day number of 1 Tishrei.
+fn new_year(year: i64) -> i64 {
+    EPOCH + elapsed_days(year) + new_year_delay(year) + 1
+}
+
+fn days_in_year(year: i64) -> i64 {
+    new_year(year + 1) - new_year(year)
+}
+
+fn month_length(year: i64, month: u32) -> u32 {
+    match month {
+        2 | 4 | 6 | 10 | 13 => 29,
+        12 if !is_leap(year) => 29,
+        8 if days_in_year(year) % 10 != 5 => 29,
+        9 if days_in_year(year) % 10 == 3 => 29,
+        _ => 30,
+    }
+}
+
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    if month < 1 || month > months_in_year(year as i64) {
+        None
+    } else {
+        Some(month_length(year as i64, month))
+    }
+}
+
+fn to_jdn(year: i64, month: u32, day: u32) -> i64 {
+    let mut jdn = new_year(year) + day as i64 - 1;
+
+    if month < 7 {
+        for m in 7..months_in_year(year) + 1 {
+            jdn += month_length(year, m) as i64;
+        }
+        for m in 1..month {
+            jdn += month_length(year, m) as i64;
+        }
+    } else {
+        for m in 7..month {
+            jdn += month_length(year, m) as i64;
+        }
+    }
+
+    jdn
+}
+
+pub fn to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    if year < 1 || day < 1 || day > days_in_month(year, month)? {
+        return None;
+    }
+
+    from_jdn(to_jdn(year as i64, month, day))
+}
+
+pub fn from_gregorian(date: NaiveDate) -> Option<(i32, u32, u32)> {
+    let day_number = jdn(date);
+    if day_number <= EPOCH {
+        return None;
+    }
+
+    let mut year = (day_number - EPOCH) * 98496 / 35975351 - 1;
+    while day_number >= to_jdn(year + 1, 7, 1) {
+        year += 1;
+    }
+
+    let mut month = if day_number < to_jdn(year, 1, 1) { 7 } else { 1 };
+    while day_number > to_jdn(year, month, month_length(year, month)) {
+        month += 1;
+    }
+    let day = day_number - to_jdn(year, month, 1) + 1;
+
+    Some((year as i32, month, day as u32))
+}
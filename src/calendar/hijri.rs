@@ -0,0 +1,67 @@
+// Tabular Islamic (Hijri) calendar with the civil epoch, 16 July 622 Julian.
+// Observation-based calendars can differ from it by a day or two.
+
+use calendar::from_jdn;
+use calendar::jdn;
+use chrono::NaiveDate;
+
+pub const MONTHS: [&[&str]; 12] = [
+    &["Muharram", "محرم"],
+    &["Safar", "صفر"],
+    &["Rabi al-Awwal", "Rabi I", "Rabiulawal", "ربيع الأول"],
+    &["Rabi al-Thani", "Rabi al-Akhir", "Rabi II", "Rabiulakhir", "ربيع الآخر", "ربيع الثاني"],
+    &["Jumada al-Ula", "Jumada al-Awwal", "Jumada I", "Jamadilawal", "جمادى الأولى"],
+    &["Jumada al-Akhirah", "Jumada al-Thani", "Jumada II", "Jamadilakhir", "جمادى الآخرة"],
+    &["Rajab", "Rejab", "رجب"],
+    &["Sha'ban", "Shaban", "Syaaban", "شعبان"],
+    &["Ramadan", "Ramadhan", "رمضان"],
+    &["Shawwal", "Syawal", "شوال"],
+    &["Dhu al-Qadah", "Dhu al-Qi'dah", "Dhul Qadah", "Zulkaedah", "ذو القعدة"],
+    &["Dhu al-Hijjah", "Dhul Hijjah", "Zulhijjah", "ذو الحجة"],
+];
+
+/// Julian day number of 1 Muharram 1 AH
+const EPOCH: i64 = 1948440;
+
+fn is_leap(year: i32) -> bool {
+    (14 + 11 * year as i64).rem_euclid(30) < 11
+}
+
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    match month {
+        12 if is_leap(year) => Some(30),
+        1..=12 if month % 2 == 1 => Some(30),
+        1..=12 => Some(29),
+        _ => None,
+    }
+}
+
+fn to_jdn(year: i32, month: u32, day: u32) -> i64 {
+    let (year, month) = (year as i64, month as i64);
+    day as i64 + (59 * (month - 1) + 1) / 2 + (year - 1) * 354 + (3 + 11 * year).div_euclid(30)
+        + EPOCH - 1
+}
+
+pub fn to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    if year < 1 || day < 1 || day > days_in_month(year, month)? {
+        return None;
+    }
+
+    from_jdn(to_jdn(year, month, day))
+}
+
+pub fn from_gregorian(date: NaiveDate) -> Option<(i32, u32, u32)> {
+    let day_number = jdn(date);
+    if day_number < EPOCH {
+        return None;
+    }
+
+    let year = ((30 * (day_number - EPOCH) + 10646) / 10631) as i32;
+    let month = (1..13)
+        .rev()
+        .find(|&m| to_jdn(year, m, 1) <= day_number)
+        .unwrap_or(1);
+    let day = day_number - to_jdn(year, month, 1) + 1;
+
+    Some((year, month, day as u32))
+}
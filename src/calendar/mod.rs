@@ -0,0 +1,294 @@
+use chrono::Datelike;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+mod hebrew;
+mod hijri;
+mod persian;
+
+/// Calendar the dates in parsed strings are written in. Years are converted
+/// to Gregorian before the date is built; two-digit years are only expanded
+/// around the reference year in the Gregorian calendar.
+///
+/// `Persian`, `Hijri` and `Hebrew` have their own months, so whole dates are
+/// converted, and a `ParserInfo` using one of them also knows its month
+/// names: "25 Shahrivar 1382", "1 Ramadan 1424" and "28 Elul 5763" are all
+/// 2003 dates.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Calendar {
+    #[default]
+    Gregorian,
+    /// Thai Buddhist Era: 2546 BE is 2003.
+    Buddhist,
+    /// Republic of China (Minguo) years: 民國92年 is 2003.
+    Minguo,
+    /// Any calendar whose years differ from Gregorian by a fixed amount,
+    /// added to each parsed year.
+    YearOffset(i32),
+    /// Solar Hijri (Jalali) calendar, as used in Iran and Afghanistan.
+    Persian,
+    /// Tabular Islamic calendar; dates fixed by moon sighting may differ
+    /// by a day or two.
+    Hijri,
+    /// Hebrew calendar, with months numbered from Nisan and Adar II as 13.
+    Hebrew,
+}
+
+impl Calendar {
+    /// Amount added to a year in this calendar to get the Gregorian year.
+    pub fn year_offset(&self) -> i32 {
+        match *self {
+            Calendar::Gregorian => 0,
+            Calendar::Buddhist => -543,
+            Calendar::Minguo => 1911,
+            Calendar::YearOffset(offset) => offset,
+            Calendar::Persian | Calendar::Hijri | Calendar::Hebrew => 0,
+        }
+    }
+}
+
+/// Whether dates in `calendar` are converted as a whole rather than by
+/// shifting the year.
+pub fn converts_dates(calendar: Calendar) -> bool {
+    matches!(calendar, Calendar::Persian | Calendar::Hijri | Calendar::Hebrew)
+}
+
+/// Names of each month of `calendar`, for calendars with their own months.
+pub fn month_names(calendar: Calendar) -> &'static [&'static [&'static str]] {
+    match calendar {
+        Calendar::Persian => &persian::MONTHS,
+        Calendar::Hijri => &hijri::MONTHS,
+        Calendar::Hebrew => &hebrew::MONTHS,
+        _ => &[],
+    }
+}
+
+pub fn to_gregorian(calendar: Calendar, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    match calendar {
+        Calendar::Persian => persian::to_gregorian(year, month, day),
+        Calendar::Hijri => hijri::to_gregorian(year, month, day),
+        Calendar::Hebrew => hebrew::to_gregorian(year, month, day),
+        _ => NaiveDate::from_ymd_opt(year.checked_add(calendar.year_offset())?, month, day),
+    }
+}
+
+pub fn from_gregorian(calendar: Calendar, date: NaiveDate) -> Option<(i32, u32, u32)> {
+    match calendar {
+        Calendar::Persian => persian::from_gregorian(date),
+        Calendar::Hijri => hijri::from_gregorian(date),
+        Calendar::Hebrew => hebrew::from_gregorian(date),
+        _ => Some((date.year().checked_sub(calendar.year_offset())?, date.month(), date.day())),
+    }
+}
+
+pub fn days_in_month(calendar: Calendar, year: i32, month: u32) -> Option<u32> {
+    match calendar {
+        Calendar::Persian => persian::days_in_month(year, month),
+        Calendar::Hijri => hijri::days_in_month(year, month),
+        Calendar::Hebrew => hebrew::days_in_month(year, month),
+        _ => {
+            let gregorian = year.checked_add(calendar.year_offset())?;
+            let next = if month == 12 {
+                NaiveDate::from_ymd_opt(gregorian.checked_add(1)?, 1, 1)
+            } else {
+                NaiveDate::from_ymd_opt(gregorian, month + 1, 1)
+            };
+            let first = NaiveDate::from_ymd_opt(gregorian, month, 1)?;
+            next.map(|next| next.signed_duration_since(first).num_days() as u32)
+        }
+    }
+}
+
+/// Julian day number of `date`.
+fn jdn(date: NaiveDate) -> i64 {
+    date.num_days_from_ce() as i64 + 1721425
+}
+
+fn from_jdn(jdn: i64) -> Option<NaiveDate> {
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(jdn - 1721425).ok()?)
+}
+
+/// Month names are compared by their letters and digits alone, so
+/// "Rabi' al-Awwal", "Rabi al Awwal" and "rabialawwal" are the same.
+fn month_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Recognize a month name of `calendar` starting at `idx`, returning the
+/// month number and the index of the last token used. Names can span
+/// several words joined by spaces, apostrophes and hyphens, as in
+/// "Dhu al-Hijjah" or "Adar II"; the longest match wins.
+pub fn find_calendar_month(calendar: Calendar, tokens: &[&str], idx: usize) -> Option<(u32, usize)> {
+    let names = month_names(calendar);
+    if names.is_empty() {
+        return None;
+    }
+
+    let mut joined = String::new();
+    let mut found = None;
+
+    for (i, token) in tokens.iter().enumerate().skip(idx).take(8) {
+        if i > idx && ["'", "’", "-", " "].contains(token) {
+            continue;
+        } else if !token.chars().all(char::is_alphabetic) {
+            break;
+        }
+
+        joined.push_str(&month_key(token));
+        let month = names
+            .iter()
+            .position(|aliases| aliases.iter().any(|name| month_key(name) == joined));
+        if let Some(month) = month {
+            found = Some((month as u32 + 1, i));
+        }
+    }
+
+    found
+}
+
+/// Key an era name is looked up by in `ParserInfo`'s era table: its letters
+/// and digits alone, so "n. Chr." is "nchr".
+pub fn era_key(name: &str) -> String {
+    month_key(name)
+}
+
+/// Era markers naming a calendar, written without periods.
+pub const ERA_MARKERS: [(&str, Calendar); 4] = [
+    ("BE", Calendar::Buddhist),
+    ("พศ", Calendar::Buddhist),
+    ("民國", Calendar::Minguo),
+    ("民国", Calendar::Minguo),
+];
+
+/// Calendar named by an era marker, written without periods.
+pub fn calendar_era(name: &str) -> Option<Calendar> {
+    ERA_MARKERS
+        .iter()
+        .find(|&&(marker, _)| marker == name)
+        .map(|&(_, calendar)| calendar)
+}
+
+/// Recognize an era marker such as "BE", "B.E.", "พ.ศ." or "民國", or one of
+/// the locale's own era names in `eras`, starting at `idx`, returning its
+/// calendar and the index of the last token used. Dotted and spaced forms
+/// ("n. Chr.") arrive split into words, periods and spaces and are joined
+/// here.
+pub fn find_calendar_era(eras: &HashMap<String, Calendar>, tokens: &[&str], idx: usize) -> Option<(Calendar, usize)> {
+    let mut joined = String::new();
+    let mut found = None;
+
+    for (i, token) in tokens.iter().enumerate().skip(idx).take(8) {
+        if i > idx && [".", " ", "-"].contains(token) {
+            continue;
+        } else if !token.chars().all(char::is_alphabetic) {
+            break;
+        }
+
+        joined.push_str(token);
+        let calendar = calendar_era(&joined).or_else(|| eras.get(&era_key(&joined)).cloned());
+        if let Some(calendar) = calendar {
+            found = Some((calendar, i));
+        }
+    }
+
+    found.map(|(calendar, last)| match tokens.get(last + 1) {
+        Some(&".") => (calendar, last + 1),
+        _ => (calendar, last),
+    })
+}
+
+mod test {
+
+    use calendar::find_calendar_era;
+    use calendar::find_calendar_month;
+    use calendar::from_gregorian;
+    use calendar::to_gregorian;
+    use calendar::Calendar;
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use tokenize;
+
+    fn era(s: &str) -> Option<(Calendar, usize)> {
+        let tokens = tokenize(s);
+        let tokens: Vec<&str> = tokens.iter().map(|t| t.as_str()).collect();
+        let eras = [("nchr".to_owned(), Calendar::Gregorian)].iter().cloned().collect();
+        find_calendar_era(&eras, &tokens, 0)
+    }
+
+    #[test]
+    fn era_markers() {
+        assert_eq!(era("BE 2546"), Some((Calendar::Buddhist, 0)));
+        assert_eq!(era("B.E. 2546"), Some((Calendar::Buddhist, 3)));
+        assert_eq!(era("พ.ศ. 2546"), Some((Calendar::Buddhist, 3)));
+        assert_eq!(era("民國92年"), Some((Calendar::Minguo, 0)));
+        assert_eq!(era("be"), None);
+        assert_eq!(era("Bed"), None);
+        assert_eq!(era("n. Chr. 2003"), Some((Calendar::Gregorian, 4)));
+        assert_eq!(era("N.Chr."), Some((Calendar::Gregorian, 3)));
+        assert_eq!(era("n 2003"), None);
+    }
+
+    #[test]
+    fn conversions() {
+        let cases = [
+            (Calendar::Persian, (1382, 6, 25), (2003, 9, 16)),
+            (Calendar::Persian, (1403, 12, 30), (2025, 3, 20)),
+            (Calendar::Persian, (1404, 1, 1), (2025, 3, 21)),
+            (Calendar::Hijri, (1424, 9, 1), (2003, 10, 27)),
+            (Calendar::Hijri, (1424, 7, 28), (2003, 9, 25)),
+            (Calendar::Hebrew, (5763, 6, 28), (2003, 9, 25)),
+            (Calendar::Hebrew, (5764, 7, 1), (2003, 9, 27)),
+            (Calendar::Hebrew, (5784, 13, 14), (2024, 3, 24)),
+        ];
+
+        for &(calendar, (y, m, d), (gy, gm, gd)) in cases.iter() {
+            let date = NaiveDate::from_ymd_opt(gy, gm, gd).unwrap();
+            assert_eq!(to_gregorian(calendar, y, m, d), Some(date), "{:?}", calendar);
+            assert_eq!(from_gregorian(calendar, date), Some((y, m, d)), "{:?}", calendar);
+        }
+
+        assert_eq!(to_gregorian(Calendar::Persian, 1402, 12, 30), None);
+        assert_eq!(to_gregorian(Calendar::Hijri, 1424, 2, 30), None);
+        assert_eq!(to_gregorian(Calendar::Hebrew, 5764, 13, 1), None);
+        // Too far out for a day count that fits in an i32
+        assert_eq!(to_gregorian(Calendar::Hijri, 2_000_000_000, 9, 1), None);
+        assert_eq!(to_gregorian(Calendar::Persian, 2_000_000_000, 1, 1), None);
+        assert_eq!(to_gregorian(Calendar::Hebrew, 2_000_000_000, 1, 1), None);
+    }
+
+    #[test]
+    fn round_trips() {
+        let start = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap();
+        for calendar in [Calendar::Persian, Calendar::Hijri, Calendar::Hebrew].iter() {
+            for days in (0..73000).step_by(7) {
+                let date = start + Duration::days(days);
+                let (y, m, d) = from_gregorian(*calendar, date).unwrap();
+                assert_eq!(to_gregorian(*calendar, y, m, d), Some(date), "{:?}", calendar);
+            }
+        }
+    }
+
+    #[test]
+    fn month_names() {
+        let month = |calendar, s: &str| {
+            let tokens = tokenize(s);
+            let tokens: Vec<&str> = tokens.iter().map(|t| t.as_str()).collect();
+            find_calendar_month(calendar, &tokens, 0)
+        };
+        assert_eq!(month(Calendar::Persian, "Shahrivar 1382"), Some((6, 0)));
+        assert_eq!(month(Calendar::Hijri, "Rabi' al-Awwal 1424"), Some((3, 5)));
+        assert_eq!(month(Calendar::Hijri, "Sha'ban"), Some((8, 2)));
+        assert_eq!(month(Calendar::Hebrew, "Adar II 5784"), Some((13, 2)));
+        assert_eq!(month(Calendar::Hebrew, "Adar 5784"), Some((12, 0)));
+        assert_eq!(month(Calendar::Gregorian, "Elul"), None);
+    }
+}
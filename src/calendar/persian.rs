@@ -0,0 +1,119 @@
+// Persian (Solar Hijri, Jalali) calendar, using the break-year algorithm
+// of jalaali-js, which agrees with the astronomical calendar for years
+// 1 to 3177.
+
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::NaiveDate;
+
+pub const MONTHS: [&[&str]; 12] = [
+    &["Farvardin", "فروردین"],
+    &["Ordibehesht", "اردیبهشت"],
+    &["Khordad", "خرداد"],
+    &["Tir", "تیر"],
+    &["Mordad", "Amordad", "مرداد"],
+    &["Shahrivar", "شهریور"],
+    &["Mehr", "مهر"],
+    &["Aban", "آبان"],
+    &["Azar", "آذر"],
+    &["Dey", "دی"],
+    &["Bahman", "بهمن"],
+    &["Esfand", "اسفند"],
+];
+
+/// Persian years in which the leap cycle is restarted
+const BREAKS: [i32; 20] = [
+    -61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210, 1635, 2060, 2097, 2192, 2262, 2324,
+    2394, 2456, 3178,
+];
+
+struct YearInfo {
+    // Years since the last leap year; 0 in leap years
+    leap: i32,
+    // Gregorian year in which the Persian year starts
+    gregorian_year: i32,
+    // Day in March of the first day of the year (Nowruz)
+    march: i32,
+}
+
+fn year_info(year: i32) -> Option<YearInfo> {
+    if year < BREAKS[0] || year >= BREAKS[BREAKS.len() - 1] {
+        return None;
+    }
+
+    let gregorian_year = year + 621;
+    let mut leap_persian = -14;
+    let mut previous = BREAKS[0];
+    let mut jump = 0;
+
+    for &brk in BREAKS[1..].iter() {
+        jump = brk - previous;
+        if year < brk {
+            break;
+        }
+        leap_persian += jump / 33 * 8 + jump % 33 / 4;
+        previous = brk;
+    }
+
+    let mut n = year - previous;
+    leap_persian += n / 33 * 8 + (n % 33 + 3) / 4;
+    if jump % 33 == 4 && jump - n == 4 {
+        leap_persian += 1;
+    }
+
+    let leap_gregorian = gregorian_year / 4 - (gregorian_year / 100 + 1) * 3 / 4 - 150;
+    let march = 20 + leap_persian - leap_gregorian;
+
+    if jump - n < 6 {
+        n = n - jump + (jump + 4) / 33 * 33;
+    }
+    let mut leap = ((n + 1) % 33 - 1) % 4;
+    if leap == -1 {
+        leap = 4;
+    }
+
+    Some(YearInfo { leap, gregorian_year, march })
+}
+
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    match month {
+        1..=6 => Some(31),
+        7..=11 => Some(30),
+        12 => year_info(year).map(|info| if info.leap == 0 { 30 } else { 29 }),
+        _ => None,
+    }
+}
+
+pub fn to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    if day < 1 || day > days_in_month(year, month)? {
+        return None;
+    }
+
+    let info = year_info(year)?;
+    let nowruz = NaiveDate::from_ymd_opt(info.gregorian_year, 3, info.march as u32)?;
+    let month = month as i64;
+    let days = (month - 1) * 31 - month / 7 * (month - 7) + day as i64 - 1;
+    nowruz.checked_add_signed(Duration::days(days))
+}
+
+pub fn from_gregorian(date: NaiveDate) -> Option<(i32, u32, u32)> {
+    let mut year = date.year() - 621;
+    let info = year_info(year)?;
+    let nowruz = NaiveDate::from_ymd_opt(info.gregorian_year, 3, info.march as u32)?;
+    let mut days = date.signed_duration_since(nowruz).num_days() as i32;
+
+    if days >= 0 {
+        if days <= 185 {
+            return Some((year, 1 + days as u32 / 31, days as u32 % 31 + 1));
+        }
+        days -= 186;
+    } else {
+        year -= 1;
+        days += 179;
+        if info.leap == 1 {
+            days += 1;
+        }
+    }
+
+    Some((year, 7 + days as u32 / 30, days as u32 % 30 + 1))
+}
@@ -0,0 +1,72 @@
+// Chinese and Japanese are written without spaces, so a run like "日上午"
+// reaches the parser as a single token. Such runs are split into known words.
+
+use Token;
+
+/// Ideographs, kana and hangul.
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3040..=0x30FF | 0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0xAC00..=0xD7AF | 0xF900..=0xFAFF
+    )
+}
+
+/// Split each unknown token containing CJK characters into the longest
+/// words `known` accepts, scanning left to right. Characters that don't
+/// start a known word become tokens of their own.
+pub fn segment<F>(tokens: Vec<Token>, known: F) -> Vec<Token>
+where
+    F: Fn(&str) -> bool,
+{
+    if !tokens.iter().any(|t| t.text.chars().any(is_cjk)) {
+        return tokens;
+    }
+
+    let mut segmented = Vec::with_capacity(tokens.len());
+
+    for token in tokens {
+        if !token.text.chars().any(is_cjk) || known(&token.text) {
+            segmented.push(token);
+            continue;
+        }
+
+        // Normalization maps characters one to one, so a token that is
+        // shorter than its span had full-width characters and its pieces
+        // can't be placed exactly
+        let exact = token.span.end - token.span.start == token.text.len();
+        let chars: Vec<(usize, char)> = token.text.char_indices().collect();
+        let offset = |i: usize| chars.get(i).map_or(token.text.len(), |&(offset, _)| offset);
+
+        let mut start = 0;
+        while start < chars.len() {
+            let end = (start + 1..chars.len() + 1)
+                .rev()
+                .find(|&end| known(&token.text[offset(start)..offset(end)]))
+                .unwrap_or(start + 1);
+            let span = if exact {
+                token.span.start + offset(start)..token.span.start + offset(end)
+            } else {
+                token.span.clone()
+            };
+            segmented.push(token.slice(offset(start)..offset(end), span));
+            start = end;
+        }
+    }
+
+    segmented
+}
+
+mod test {
+
+    use cjk::segment;
+    use tokenize_spans;
+
+    #[test]
+    fn longest_known_words() {
+        let known = |t: &str| ["日", "上午", "月曜日", "月"].contains(&t);
+        let tokens = segment(tokenize_spans("25日上午 日月曜日 未知"), known);
+        let words: Vec<&str> = tokens.iter().map(|t| &*t.text).collect();
+        assert_eq!(words, vec!["25", "日", "上午", " ", "日", "月曜日", " ", "未", "知"]);
+        assert_eq!(tokens[2].span, 5..11);
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::FixedOffset;
+use chrono::Local;
+use chrono::NaiveDateTime;
+use chrono::Utc;
+use std::fmt::Debug;
+
+/// Where the parser gets "now" from: the reference year two-digit years
+/// resolve around, and the default date when `parse` isn't given one.
+///
+/// ```
+/// extern crate chrono;
+/// extern crate dtparse;
+///
+/// use chrono::NaiveDate;
+/// use dtparse::FixedClock;
+/// use dtparse::Parser;
+/// use dtparse::ParserInfo;
+/// use std::collections::HashMap;
+///
+/// let now = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(10, 49, 41).unwrap();
+/// let info = ParserInfo::builder().clock(FixedClock(now)).build().unwrap();
+/// let mut parser = Parser::new(info);
+/// let mut parse = |s: &str| parser.parse(s, None, None, false, false, None, false, HashMap::new()).unwrap().0;
+///
+/// assert_eq!(parse("10:36"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(10, 36, 0).unwrap());
+/// assert_eq!(parse("1/2/69"), NaiveDate::from_ymd_opt(1969, 1, 2).unwrap().and_hms_opt(0, 0, 0).unwrap());
+/// ```
+pub trait Clock: Debug + Send + Sync {
+    /// The current date and time, in whatever timezone the clock keeps.
+    fn now(&self) -> NaiveDateTime;
+}
+
+/// The host's local time. The default.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Local::now().naive_local()
+    }
+}
+
+/// Always the same instant, for reproducible results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedClock(pub NaiveDateTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> NaiveDateTime {
+        self.0
+    }
+}
+
+/// The current time at a fixed offset from UTC, regardless of the host's
+/// timezone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OffsetClock(pub FixedOffset);
+
+impl Clock for OffsetClock {
+    fn now(&self) -> NaiveDateTime {
+        Utc::now().with_timezone(&self.0).naive_local()
+    }
+}
+
+mod test {
+
+    use chrono::Duration;
+    use chrono::FixedOffset;
+    use chrono::Utc;
+
+    use clock::Clock;
+    use clock::OffsetClock;
+
+    #[test]
+    fn offset_clock() {
+        let ahead = OffsetClock(FixedOffset::east_opt(5 * 3600).unwrap()).now() - Utc::now().naive_utc();
+        assert!((ahead - Duration::hours(5)).num_seconds().abs() < 5);
+    }
+}
@@ -0,0 +1,104 @@
+// Spoken clock times: "half past three", "quarter to five", "ten o'clock",
+// "twenty past 9". Spelled-out numbers have already been replaced with digits
+// by the time these are matched.
+
+fn minutes(word: &str) -> Option<i32> {
+    match word {
+        "quarter" => Some(15),
+        "half" => Some(30),
+        _ => word.parse::<i32>().ok().filter(|m| 0 < *m && *m < 60),
+    }
+}
+
+/// Recognize a colloquial clock phrase starting at `idx`, returning the hour
+/// (0-23, before any AM/PM adjustment), minute, and index of the last token
+/// in the phrase.
+pub fn find_clock_phrase(tokens: &[&str], idx: usize) -> Option<(i32, i32, usize)> {
+    // Positions of the non-whitespace tokens from `idx` onwards
+    let words: Vec<usize> = (idx..tokens.len()).filter(|&i| tokens[i] != " ").take(6).collect();
+    let word = |n: usize| words.get(n).map(|&i| tokens[i].to_lowercase()).unwrap_or_default();
+
+    if words.first() != Some(&idx) {
+        return None;
+    }
+
+    // ten o'clock
+    if let Ok(hour) = tokens[idx].parse::<i32>() {
+        if (0..=24).contains(&hour) {
+            if word(1) == "oclock" {
+                return Some((hour % 24, 0, words[1]));
+            } else if word(1) == "o" && (word(2) == "'" || word(2) == "\u{2019}") && word(3) == "clock" {
+                return Some((hour % 24, 0, words[3]));
+            }
+        }
+    }
+
+    // [a] quarter/half/N [minutes] past/to H
+    let mut n = if word(0) == "a" { 1 } else { 0 };
+    let named = word(n) == "quarter" || word(n) == "half";
+    let minute = minutes(&word(n))?;
+    n += 1;
+
+    let unit = word(n) == "minute" || word(n) == "minutes";
+    if unit {
+        n += 1;
+    }
+
+    let before = match word(n).as_str() {
+        "past" | "after" => false,
+        "to" | "till" | "til" | "before" => true,
+        _ => return None,
+    };
+
+    // A bare number needs "minutes" or "past"/"to" after it, so that dates
+    // such as "1 of 2" or "5 before 6" aren't read as times
+    if !named && !unit && word(n) != "past" && word(n) != "to" {
+        return None;
+    }
+    n += 1;
+
+    let hour = word(n).parse::<i32>().ok()?;
+    if before && (0..=24).contains(&hour) {
+        let hour = match hour {
+            0 => 23,
+            1 => 12,
+            _ => hour - 1,
+        };
+        Some((hour, 60 - minute, words[n]))
+    } else if !before && (0..24).contains(&hour) {
+        Some((hour, minute, words[n]))
+    } else {
+        None
+    }
+}
+
+mod test {
+
+    use colloquial::find_clock_phrase;
+    use numbers::replace_number_words;
+    use tokenize_spans;
+
+    fn clock(s: &str) -> Option<(i32, i32)> {
+        let tokens = replace_number_words(tokenize_spans(s));
+        let tokens: Vec<&str> = tokens.iter().map(|t| &*t.text).collect();
+        find_clock_phrase(&tokens, 0).map(|(h, m, _)| (h, m))
+    }
+
+    #[test]
+    fn clock_phrases() {
+        assert_eq!(clock("half past three"), Some((3, 30)));
+        assert_eq!(clock("a quarter past 10"), Some((10, 15)));
+        assert_eq!(clock("quarter to five"), Some((4, 45)));
+        assert_eq!(clock("quarter to one"), Some((12, 45)));
+        assert_eq!(clock("twenty past 9"), Some((9, 20)));
+        assert_eq!(clock("ten minutes to 6"), Some((5, 50)));
+        assert_eq!(clock("ten o'clock"), Some((10, 0)));
+        assert_eq!(clock("10 of May"), None);
+        assert_eq!(clock("1 of 2"), None);
+        assert_eq!(clock("25 of 10"), None);
+        assert_eq!(clock("5 after 3"), None);
+        assert_eq!(clock("5 minutes after 3"), Some((3, 5)));
+        assert_eq!(clock("quarter after 3"), Some((3, 15)));
+        assert_eq!(clock("10:30"), None);
+    }
+}
@@ -0,0 +1,118 @@
+// Japanese era (wareki) years: "令和5年" is 2023, "平成元年" is 1989.
+
+struct Era {
+    names: &'static [&'static str],
+    /// First and last days as (year, month, day); the current era has no end.
+    start: (i32, u32, u32),
+    end: Option<(i32, u32, u32)>,
+}
+
+/// Eras from Meiji onwards.
+const ERAS: [Era; 5] = [
+    Era { names: &["明治", "meiji"], start: (1868, 1, 25), end: Some((1912, 7, 29)) },
+    Era { names: &["大正", "taisho", "taishō"], start: (1912, 7, 30), end: Some((1926, 12, 24)) },
+    Era { names: &["昭和", "showa", "shōwa"], start: (1926, 12, 25), end: Some((1989, 1, 7)) },
+    Era { names: &["平成", "heisei"], start: (1989, 1, 8), end: Some((2019, 4, 30)) },
+    Era { names: &["令和", "reiwa"], start: (2019, 5, 1), end: None },
+];
+
+/// Written in place of 1 for the first year of an era (元年).
+pub const FIRST_YEAR: &str = "元";
+
+/// Every era name, with the era it names; the lexicon looks names up.
+pub fn era_names() -> impl Iterator<Item = (&'static str, usize)> {
+    ERAS.iter()
+        .enumerate()
+        .flat_map(|(i, era)| era.names.iter().map(move |&name| (name, i)))
+}
+
+/// Recognize an era year starting at `idx`, where `tokens[idx]` names era
+/// number `era` from `era_names`, e.g. "平成 15 年" or "令和元年" (already
+/// split into words). Returns the Gregorian year and the index of the last
+/// token used; a trailing 年 is included. Years after the era ended, such as
+/// 平成99年, aren't recognized; see `contains` for checking the whole date.
+pub fn find_era_year(era_index: usize, tokens: &[&str], idx: usize) -> Option<(i32, usize)> {
+    let era = &ERAS[era_index];
+
+    let mut i = idx + 1;
+    while tokens.get(i).map_or(false, |&t| t == " ") {
+        i += 1;
+    }
+
+    let year = match tokens.get(i) {
+        Some(&t) if t == FIRST_YEAR => 1,
+        Some(t) => t.parse::<i32>().ok().filter(|&y| y >= 1)?,
+        None => return None,
+    };
+
+    if tokens.get(i + 1).map_or(false, |&t| t == "年") {
+        i += 1;
+    }
+
+    let year = (era.start.0 - 1).checked_add(year)?;
+    if contains(era_index, year, None, None) {
+        Some((year, i))
+    } else {
+        None
+    }
+}
+
+/// Whether a Gregorian date can fall within era number `era`. A missing
+/// month or day matches any, so 平成31年 is in Heisei but 平成31年5月1日,
+/// after the era ended on April 30th, isn't.
+pub fn contains(era: usize, year: i32, month: Option<u32>, day: Option<u32>) -> bool {
+    let era = &ERAS[era];
+    let first = (year, month.unwrap_or(1), day.unwrap_or(1));
+    let last = (year, month.unwrap_or(12), day.unwrap_or(31));
+    last >= era.start && era.end.map_or(true, |end| first <= end)
+}
+
+mod test {
+
+    use era::contains;
+    use era::era_names;
+    use era::find_era_year;
+    use lexicon::Lexicon;
+    use tokenize;
+    use ParserInfo;
+
+    fn era_year(s: &str) -> Option<i32> {
+        let tokens = tokenize(s);
+        let tokens: Vec<&str> = tokens.iter().map(|t| t.as_str()).collect();
+        let era = Lexicon::new(&ParserInfo::default()).get(tokens[0])?.era?;
+        find_era_year(era, &tokens, 0).map(|(year, _)| year)
+    }
+
+    #[test]
+    fn era_years() {
+        assert_eq!(era_year("令和5年"), Some(2023));
+        assert_eq!(era_year("平成15年"), Some(2003));
+        assert_eq!(era_year("Heisei 15"), Some(2003));
+        assert_eq!(era_year("昭和64年"), Some(1989));
+        assert_eq!(era_year("明治"), None);
+        assert_eq!(era_year("平成 0"), None);
+        assert_eq!(era_year("平成31年"), Some(2019));
+        assert_eq!(era_year("平成32年"), None);
+        assert_eq!(era_year("平成99年"), None);
+        assert_eq!(era_year("昭和70年"), None);
+        assert_eq!(era_year("明治45年"), Some(1912));
+        assert_eq!(era_year("大正16年"), None);
+        assert_eq!(era_year("令和99年"), Some(2117));
+        assert_eq!(era_year("令和2147483647年"), None);
+    }
+
+    #[test]
+    fn era_boundaries() {
+        let era = |name| era_names().find(|&(n, _)| n == name).unwrap().1;
+        let (heisei, reiwa) = (era("heisei"), era("reiwa"));
+        assert!(contains(heisei, 2019, Some(4), Some(30)));
+        assert!(!contains(heisei, 2019, Some(5), Some(1)));
+        assert!(!contains(heisei, 2019, Some(5), None));
+        assert!(contains(heisei, 1989, Some(1), Some(8)));
+        assert!(!contains(heisei, 1989, Some(1), Some(7)));
+        assert!(contains(reiwa, 2019, Some(5), Some(1)));
+        assert!(!contains(reiwa, 2019, Some(4), Some(1)));
+        assert!(contains(reiwa, 2019, None, None));
+        assert!(contains(reiwa, 9999, Some(12), Some(31)));
+    }
+}
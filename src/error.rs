@@ -0,0 +1,174 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+/// What went wrong while parsing. Kinds are stable across releases and are
+/// the part of a `ParseError` to match on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// A token that isn't a date component, timezone or skippable word.
+    UnrecognizedToken,
+    /// A number that is malformed or too large for its component.
+    InvalidNumber,
+    /// The input ends where another component was expected ("Jan-").
+    UnexpectedEnd,
+    /// A date component given twice, or more than three numbers that could
+    /// be a year, month or day.
+    AmbiguousDate,
+    /// "am" or "pm" with no hour before it.
+    AmPmWithoutHour,
+    InvalidYear,
+    InvalidMonth,
+    InvalidDay,
+    InvalidHour,
+    InvalidMinute,
+    InvalidSecond,
+    /// A UTC offset that can't be represented, such as "+9999".
+    TimezoneUnsupported,
+}
+
+impl ErrorKind {
+    fn description(&self) -> &'static str {
+        match *self {
+            ErrorKind::UnrecognizedToken => "unrecognized token",
+            ErrorKind::InvalidNumber => "invalid number",
+            ErrorKind::UnexpectedEnd => "unexpected end of input",
+            ErrorKind::AmbiguousDate => "ambiguous date",
+            ErrorKind::AmPmWithoutHour => "am/pm without an hour",
+            ErrorKind::InvalidYear => "invalid year",
+            ErrorKind::InvalidMonth => "invalid month",
+            ErrorKind::InvalidDay => "invalid day",
+            ErrorKind::InvalidHour => "invalid hour",
+            ErrorKind::InvalidMinute => "invalid minute",
+            ErrorKind::InvalidSecond => "invalid second",
+            ErrorKind::TimezoneUnsupported => "unsupported timezone offset",
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// A parse failure, pointing at the part of the input that caused it.
+///
+/// ```
+/// use dtparse::parse;
+/// use dtparse::ErrorKind;
+///
+/// let err = parse("2003-02-00").unwrap_err();
+/// assert_eq!(err.kind(), ErrorKind::InvalidDay);
+/// assert_eq!(err.token(), "00");
+/// assert_eq!(err.span(), 8..10);
+/// assert_eq!(err.to_string(), "invalid day \"00\" at 8..10");
+/// assert_eq!(err.diagnostic(), "invalid day \"00\" at 8..10\n2003-02-00\n        ^^");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ErrorKind,
+    token: String,
+    span: Range<usize>,
+    input: String,
+}
+
+impl ParseError {
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The offending part of the input; empty for `UnexpectedEnd`.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Byte range of `token` within `input`.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The error message, followed by the input line containing the
+    /// offending token and carets underneath it.
+    pub fn diagnostic(&self) -> String {
+        let line_start = self.input[..self.span.start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.input[self.span.start..]
+            .find('\n')
+            .map_or(self.input.len(), |i| self.span.start + i);
+
+        // Keep tabs so the carets line up with the text above them
+        let indent: String = self.input[line_start..self.span.start]
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let width = self.input[self.span.start..self.span.end.min(line_end)].chars().count();
+
+        format!(
+            "{}\n{}\n{}{}",
+            self,
+            &self.input[line_start..line_end],
+            indent,
+            "^".repeat(width.max(1))
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.kind == ErrorKind::UnexpectedEnd {
+            write!(f, "{} at {}", self.kind, self.span.start)
+        } else {
+            write!(f, "{} {:?} at {}..{}", self.kind, self.token, self.span.start, self.span.end)
+        }
+    }
+}
+
+impl Error for ParseError {
+    fn description(&self) -> &str {
+        self.kind.description()
+    }
+}
+
+/// Error of `kind` for the part of `input` at `span`. `UnexpectedEnd` always
+/// points just past the end of the input, and a span that isn't within the
+/// input covers all of it.
+pub fn error_at(kind: ErrorKind, input: &str, span: Range<usize>) -> ParseError {
+    let span = if kind == ErrorKind::UnexpectedEnd {
+        input.len()..input.len()
+    } else if input.get(span.clone()).is_none() {
+        0..input.len()
+    } else {
+        span
+    };
+
+    ParseError {
+        kind,
+        token: input[span.clone()].to_owned(),
+        span,
+        input: input.to_owned(),
+    }
+}
+
+mod test {
+
+    use error::error_at;
+    use error::ErrorKind;
+
+    #[test]
+    fn diagnostics() {
+        let err = error_at(ErrorKind::UnrecognizedToken, "Sep 25 2003 foo", 12..15);
+        assert_eq!(err.diagnostic(), "unrecognized token \"foo\" at 12..15\nSep 25 2003 foo\n            ^^^");
+
+        let err = error_at(ErrorKind::UnexpectedEnd, "Jan-", 3..4);
+        assert_eq!(err.token(), "");
+        assert_eq!(err.diagnostic(), "unexpected end of input at 4\nJan-\n    ^");
+
+        // Carets count characters, not bytes, and only the offending line is shown
+        let err = error_at(ErrorKind::InvalidDay, "2003年\n2月30日", 12..14);
+        assert_eq!(err.diagnostic(), "invalid day \"30\" at 12..14\n2月30日\n  ^^");
+    }
+}
@@ -0,0 +1,245 @@
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use weekday::nth_weekday_of_month;
+use weekday::DayOfWeek;
+
+/// How to find the date of a holiday in a given year.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum HolidayRule {
+    /// Same month and day every year, e.g. Christmas on December 25th.
+    Fixed { month: u32, day: u32 },
+    /// The `n`th `weekday` of a month, e.g. the fourth Thursday of November.
+    /// Negative values of `n` count back from the end of the month.
+    NthWeekday { month: u32, weekday: DayOfWeek, n: i32 },
+    /// A number of days before (negative) or after Western Easter Sunday.
+    EasterOffset(i32),
+}
+
+impl HolidayRule {
+    /// Date the holiday falls on in `year`, if it exists in that year.
+    pub fn resolve(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            HolidayRule::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayRule::NthWeekday { month, weekday, n } => {
+                let day = nth_weekday_of_month(year, month, weekday, n)?;
+                NaiveDate::from_ymd_opt(year, month, day)
+            }
+            HolidayRule::EasterOffset(days) => {
+                let easter = easter(year)?;
+                easter.checked_add_signed(::chrono::Duration::days(days as i64))
+            }
+        }
+    }
+}
+
+/// A source of named holidays consulted by the parser.
+///
+/// The parser hands over candidate names already normalized by
+/// [`normalize_name`](fn.normalize_name.html), e.g. "New Year's Eve" is
+/// looked up as `"new years eve"`.
+pub trait HolidayCalendar {
+    fn holiday(&self, name: &str) -> Option<HolidayRule>;
+}
+
+/// A table of holidays keyed by name. Use `us()` or `uk()` for the built-in
+/// calendars, or `new()` and `insert` for custom (e.g. corporate) holidays.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct HolidaySet {
+    holidays: HashMap<String, HolidayRule>,
+}
+
+impl HolidaySet {
+    pub fn new() -> Self {
+        HolidaySet::default()
+    }
+
+    /// Federal and commonly observed holidays in the United States.
+    pub fn us() -> Self {
+        let mut set = HolidaySet::new();
+        set.insert("New Year's Day", HolidayRule::Fixed { month: 1, day: 1 })
+            .insert("Martin Luther King Day", HolidayRule::NthWeekday { month: 1, weekday: DayOfWeek::Monday, n: 3 })
+            .insert("Martin Luther King Jr. Day", HolidayRule::NthWeekday { month: 1, weekday: DayOfWeek::Monday, n: 3 })
+            .insert("MLK Day", HolidayRule::NthWeekday { month: 1, weekday: DayOfWeek::Monday, n: 3 })
+            .insert("Valentine's Day", HolidayRule::Fixed { month: 2, day: 14 })
+            .insert("Presidents' Day", HolidayRule::NthWeekday { month: 2, weekday: DayOfWeek::Monday, n: 3 })
+            .insert("Washington's Birthday", HolidayRule::NthWeekday { month: 2, weekday: DayOfWeek::Monday, n: 3 })
+            .insert("Good Friday", HolidayRule::EasterOffset(-2))
+            .insert("Easter", HolidayRule::EasterOffset(0))
+            .insert("Easter Sunday", HolidayRule::EasterOffset(0))
+            .insert("Mother's Day", HolidayRule::NthWeekday { month: 5, weekday: DayOfWeek::Sunday, n: 2 })
+            .insert("Memorial Day", HolidayRule::NthWeekday { month: 5, weekday: DayOfWeek::Monday, n: -1 })
+            .insert("Father's Day", HolidayRule::NthWeekday { month: 6, weekday: DayOfWeek::Sunday, n: 3 })
+            .insert("Juneteenth", HolidayRule::Fixed { month: 6, day: 19 })
+            .insert("Independence Day", HolidayRule::Fixed { month: 7, day: 4 })
+            .insert("Fourth of July", HolidayRule::Fixed { month: 7, day: 4 })
+            .insert("Labor Day", HolidayRule::NthWeekday { month: 9, weekday: DayOfWeek::Monday, n: 1 })
+            .insert("Columbus Day", HolidayRule::NthWeekday { month: 10, weekday: DayOfWeek::Monday, n: 2 })
+            .insert("Halloween", HolidayRule::Fixed { month: 10, day: 31 })
+            .insert("Veterans Day", HolidayRule::Fixed { month: 11, day: 11 })
+            .insert("Thanksgiving", HolidayRule::NthWeekday { month: 11, weekday: DayOfWeek::Thursday, n: 4 })
+            .insert("Thanksgiving Day", HolidayRule::NthWeekday { month: 11, weekday: DayOfWeek::Thursday, n: 4 })
+            .insert("Christmas Eve", HolidayRule::Fixed { month: 12, day: 24 })
+            .insert("Christmas", HolidayRule::Fixed { month: 12, day: 25 })
+            .insert("Christmas Day", HolidayRule::Fixed { month: 12, day: 25 })
+            .insert("New Year's Eve", HolidayRule::Fixed { month: 12, day: 31 });
+        set
+    }
+
+    /// Bank holidays and common observances in the United Kingdom.
+    pub fn uk() -> Self {
+        let mut set = HolidaySet::new();
+        set.insert("New Year's Day", HolidayRule::Fixed { month: 1, day: 1 })
+            .insert("Valentine's Day", HolidayRule::Fixed { month: 2, day: 14 })
+            .insert("St Patrick's Day", HolidayRule::Fixed { month: 3, day: 17 })
+            .insert("Saint Patrick's Day", HolidayRule::Fixed { month: 3, day: 17 })
+            .insert("Mothering Sunday", HolidayRule::EasterOffset(-21))
+            .insert("Good Friday", HolidayRule::EasterOffset(-2))
+            .insert("Easter", HolidayRule::EasterOffset(0))
+            .insert("Easter Sunday", HolidayRule::EasterOffset(0))
+            .insert("Easter Monday", HolidayRule::EasterOffset(1))
+            .insert("Early May Bank Holiday", HolidayRule::NthWeekday { month: 5, weekday: DayOfWeek::Monday, n: 1 })
+            .insert("May Day", HolidayRule::NthWeekday { month: 5, weekday: DayOfWeek::Monday, n: 1 })
+            .insert("Spring Bank Holiday", HolidayRule::NthWeekday { month: 5, weekday: DayOfWeek::Monday, n: -1 })
+            .insert("Summer Bank Holiday", HolidayRule::NthWeekday { month: 8, weekday: DayOfWeek::Monday, n: -1 })
+            .insert("Halloween", HolidayRule::Fixed { month: 10, day: 31 })
+            .insert("Bonfire Night", HolidayRule::Fixed { month: 11, day: 5 })
+            .insert("Guy Fawkes Night", HolidayRule::Fixed { month: 11, day: 5 })
+            .insert("Remembrance Day", HolidayRule::Fixed { month: 11, day: 11 })
+            .insert("Christmas Eve", HolidayRule::Fixed { month: 12, day: 24 })
+            .insert("Christmas", HolidayRule::Fixed { month: 12, day: 25 })
+            .insert("Christmas Day", HolidayRule::Fixed { month: 12, day: 25 })
+            .insert("Boxing Day", HolidayRule::Fixed { month: 12, day: 26 })
+            .insert("New Year's Eve", HolidayRule::Fixed { month: 12, day: 31 });
+        set
+    }
+
+    /// Add a holiday, replacing any existing holiday with the same name.
+    pub fn insert(&mut self, name: &str, rule: HolidayRule) -> &mut Self {
+        self.holidays.insert(normalize_name(name), rule);
+        self
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<HolidayRule> {
+        self.holidays.remove(&normalize_name(name))
+    }
+
+    pub fn len(&self) -> usize {
+        self.holidays.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.holidays.is_empty()
+    }
+
+    /// Dates of every holiday in the set for a given year.
+    pub fn dates(&self, year: i32) -> Vec<NaiveDate> {
+        let mut dates: Vec<NaiveDate> = self.holidays
+            .values()
+            .filter_map(|rule| rule.resolve(year))
+            .collect();
+        dates.sort();
+        dates.dedup();
+        dates
+    }
+}
+
+impl HolidayCalendar for HolidaySet {
+    fn holiday(&self, name: &str) -> Option<HolidayRule> {
+        self.holidays.get(name).cloned()
+    }
+}
+
+/// Canonical form used for holiday lookups: lowercase words separated by
+/// single spaces, with apostrophes and periods dropped ("St. Patrick's Day"
+/// becomes `"st patricks day"`).
+pub fn normalize_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    let mut pending_space = false;
+
+    for c in name.chars() {
+        if c.is_alphanumeric() {
+            if pending_space && !normalized.is_empty() {
+                normalized.push(' ');
+            }
+            pending_space = false;
+            normalized.extend(c.to_lowercase());
+        } else if c == '\'' || c == '\u{2019}' || c == '.' {
+            continue;
+        } else {
+            pending_space = true;
+        }
+    }
+
+    normalized
+}
+
+/// Date of Western (Gregorian) Easter Sunday, using the anonymous Gregorian
+/// algorithm.
+pub fn easter(year: i32) -> Option<NaiveDate> {
+    if year < 1 {
+        return None;
+    }
+
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+mod test {
+
+    use chrono::NaiveDate;
+    use holiday::easter;
+    use holiday::normalize_name;
+    use holiday::HolidayCalendar;
+    use holiday::HolidayRule;
+    use holiday::HolidaySet;
+
+    #[test]
+    fn easter_examples() {
+        assert_eq!(easter(2003), NaiveDate::from_ymd_opt(2003, 4, 20));
+        assert_eq!(easter(2018), NaiveDate::from_ymd_opt(2018, 4, 1));
+        assert_eq!(easter(2019), NaiveDate::from_ymd_opt(2019, 4, 21));
+    }
+
+    #[test]
+    fn normalized_names() {
+        assert_eq!(normalize_name("New Year's Eve"), "new years eve");
+        assert_eq!(normalize_name("  St. Patrick's   Day "), "st patricks day");
+        assert_eq!(normalize_name("Presidents' Day"), "presidents day");
+    }
+
+    #[test]
+    fn builtin_rules() {
+        let us = HolidaySet::us();
+        let uk = HolidaySet::uk();
+
+        let thanksgiving = us.holiday("thanksgiving").unwrap();
+        assert_eq!(thanksgiving.resolve(2003), NaiveDate::from_ymd_opt(2003, 11, 27));
+        let good_friday = uk.holiday("good friday").unwrap();
+        assert_eq!(good_friday.resolve(2003), NaiveDate::from_ymd_opt(2003, 4, 18));
+        assert_eq!(uk.holiday("thanksgiving"), None);
+    }
+
+    #[test]
+    fn custom_holidays() {
+        let mut set = HolidaySet::new();
+        set.insert("Founders' Day", HolidayRule::Fixed { month: 3, day: 9 });
+        assert_eq!(set.holiday("founders day"), Some(HolidayRule::Fixed { month: 3, day: 9 }));
+        assert_eq!(set.remove("FOUNDERS DAY"), Some(HolidayRule::Fixed { month: 3, day: 9 }));
+        assert!(set.is_empty());
+    }
+}
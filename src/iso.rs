@@ -0,0 +1,219 @@
+// Canonical ISO 8601 and RFC 3339 timestamps, such as "2003-09-25",
+// "2003-09-25 10:49" or "2003-09-25T10:49:41.5-03:00", read straight from the
+// bytes of the input. Anything else, including components out of range, is
+// left to the tokenizing parser.
+
+use std::ops::Range;
+
+/// A timestamp of the form `YYYY-MM-DD[(T| )hh:mm[:ss[.f…]][Z|±hh[[:]mm]]]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsoTimestamp<'a> {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32,
+    pub time: Option<IsoTime<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IsoTime<'a> {
+    /// "T" or " ", between the date and the time.
+    pub separator: &'a str,
+    pub hour: i32,
+    pub minute: i32,
+    /// Seconds and microseconds. Fraction digits past the sixth are dropped.
+    pub second: Option<(i32, i32)>,
+    /// Input covered by the hours, minutes and seconds.
+    pub span: Range<usize>,
+    /// Seconds east of UTC ("Z" is 0) and the input it was read from.
+    pub offset: Option<(i32, Range<usize>)>,
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).cloned()
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.eat(byte) {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Exactly `n` ASCII digits, as a number below `limit`.
+    fn number(&mut self, n: usize, limit: i32) -> Option<i32> {
+        let digits = self.bytes.get(self.pos..self.pos + n)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+
+        self.pos += n;
+        let value = digits.iter().fold(0, |value, b| value * 10 + i32::from(b - b'0'));
+        Some(value).filter(|&value| value < limit)
+    }
+
+    /// One or more digits after a decimal point, as microseconds.
+    fn fraction(&mut self) -> Option<i32> {
+        let start = self.pos;
+        while self.peek().map_or(false, |b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+
+        let digits = &self.bytes[start..self.pos];
+        if digits.is_empty() {
+            return None;
+        }
+
+        let digit = |i: usize| digits.get(i).map_or(0, |b| i32::from(b - b'0'));
+        Some((0..6).fold(0, |micros, i| micros * 10 + digit(i)))
+    }
+
+    /// "Z", or a sign followed by hours and optional minutes.
+    fn offset(&mut self) -> Option<i32> {
+        if self.eat(b'Z') {
+            return Some(0);
+        }
+
+        let sign = match self.peek()? {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        self.pos += 1;
+
+        let hours = self.number(2, 24)?;
+        let minutes = if self.peek().is_none() {
+            0
+        } else {
+            self.eat(b':');
+            self.number(2, 60)?
+        };
+
+        Some(sign * (hours * 3600 + minutes * 60))
+    }
+}
+
+/// Read `s` as a canonical ISO 8601 timestamp, or `None` at the first byte
+/// that doesn't fit the shape.
+pub fn parse_iso(s: &str) -> Option<IsoTimestamp<'_>> {
+    let mut cursor = Cursor {
+        bytes: s.as_bytes(),
+        pos: 0,
+    };
+
+    let year = cursor.number(4, 10000)?;
+    cursor.expect(b'-')?;
+    let month = cursor.number(2, 13).filter(|&month| month > 0)?;
+    cursor.expect(b'-')?;
+    let day = cursor.number(2, 32).filter(|&day| day > 0)?;
+
+    let mut timestamp = IsoTimestamp {
+        year,
+        month,
+        day,
+        time: None,
+    };
+    if cursor.peek().is_none() {
+        return Some(timestamp);
+    }
+
+    let separator = match cursor.peek()? {
+        b'T' => "T",
+        b' ' => " ",
+        _ => return None,
+    };
+    cursor.pos += 1;
+
+    let start = cursor.pos;
+    let hour = cursor.number(2, 24)?;
+    cursor.expect(b':')?;
+    let minute = cursor.number(2, 60)?;
+    let second = if cursor.eat(b':') {
+        let second = cursor.number(2, 60)?;
+        let micros = if cursor.eat(b'.') { cursor.fraction()? } else { 0 };
+        Some((second, micros))
+    } else {
+        None
+    };
+    let span = start..cursor.pos;
+
+    let offset = if cursor.peek().is_some() {
+        let start = cursor.pos;
+        let offset = cursor.offset()?;
+        Some((offset, start..cursor.pos))
+    } else {
+        None
+    };
+
+    if cursor.peek().is_some() {
+        return None;
+    }
+
+    timestamp.time = Some(IsoTime {
+        separator,
+        hour,
+        minute,
+        second,
+        span,
+        offset,
+    });
+    Some(timestamp)
+}
+
+mod test {
+
+    use iso::parse_iso;
+
+    #[test]
+    fn canonical_shapes() {
+        let date = parse_iso("2003-09-25").unwrap();
+        assert_eq!((date.year, date.month, date.day), (2003, 9, 25));
+        assert_eq!(date.time, None);
+
+        let time = parse_iso("2003-09-25T10:49:41.5-03:00").unwrap().time.unwrap();
+        assert_eq!((time.hour, time.minute, time.second), (10, 49, Some((41, 500000))));
+        assert_eq!(time.span, 11..21);
+        assert_eq!(time.offset, Some((-3 * 3600, 21..27)));
+
+        let time = parse_iso("2003-09-25 10:49Z").unwrap().time.unwrap();
+        assert_eq!((time.separator, time.second), (" ", None));
+        assert_eq!(time.offset, Some((0, 16..17)));
+
+        let time = parse_iso("2003-09-25T10:49:41.123456789+0530").unwrap().time.unwrap();
+        assert_eq!(time.second, Some((41, 123456)));
+        assert_eq!(time.offset.map(|(offset, _)| offset), Some(5 * 3600 + 30 * 60));
+
+        let time = parse_iso("2003-09-25T10:49:41+05").unwrap().time.unwrap();
+        assert_eq!(time.offset.map(|(offset, _)| offset), Some(5 * 3600));
+    }
+
+    #[test]
+    fn deviations() {
+        let inputs = [
+            "", "2003", "2003-09", "20030925", "2003-9-25", "2003/09/25", "2003-13-25", "2003-09-32",
+            "2003-00-25", " 2003-09-25", "2003-09-25 ", "2003-09-25T", "2003-09-25t10:49",
+            "2003-09-25T10", "2003-09-25T24:00", "2003-09-25T10:60", "2003-09-25T10:49:60",
+            "2003-09-25T10:49:41.", "2003-09-25T10:49:41,5", "2003-09-25T10:49z",
+            "2003-09-25T10:49+3", "2003-09-25T10:49+03:0", "2003-09-25T10:49+03:", "2003-09-25+03:00",
+            "2003-09-25T10:49 UTC", "２００３-09-25", "2003-09-25T10:49:41Z garbage",
+        ];
+        for s in inputs.iter() {
+            assert_eq!(parse_iso(s), None, "{:?}", s);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt;
+
+use calendar::ERA_MARKERS;
+use era::era_names;
+use Calendar;
+use ParserInfo;
+
+/// Every meaning a `ParserInfo` gives one word. Values are as stored in its
+/// tables, so months count from 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Entry {
+    pub jump: bool,
+    pub weekday: Option<usize>,
+    pub month: Option<usize>,
+    pub hms: Option<usize>,
+    pub ymd: Option<usize>,
+    pub ampm: Option<usize>,
+    pub utczone: bool,
+    pub pertain: bool,
+    pub named_time: Option<usize>,
+    pub relative_day: Option<i64>,
+    pub tzoffset: Option<i32>,
+    /// Japanese era, as numbered by `era::era_names`.
+    pub era: Option<usize>,
+    /// Calendar named by an era marker.
+    pub calendar: Option<Calendar>,
+}
+
+impl Entry {
+    /// Whether the word is in any vocabulary category or names an era.
+    /// Time zone offsets don't count.
+    pub fn is_vocabulary(&self) -> bool {
+        self.jump || self.weekday.is_some() || self.month.is_some() || self.hms.is_some()
+            || self.ymd.is_some() || self.ampm.is_some() || self.utczone || self.pertain
+            || self.named_time.is_some() || self.relative_day.is_some() || self.era.is_some()
+            || self.calendar.is_some()
+    }
+}
+
+/// The words of a `ParserInfo` compiled into a trie over their folded
+/// characters, so that a token's meanings take a single walk from the root,
+/// without lowercasing it into a new string first.
+#[derive(Clone, Default)]
+pub struct Lexicon {
+    // The root is the first node; an empty lexicon has none
+    nodes: Vec<Node>,
+    words: usize,
+}
+
+#[derive(Clone, Default)]
+struct Node {
+    // Sorted by character, with the index of the node each leads to
+    children: Vec<(char, usize)>,
+    entry: Option<Entry>,
+}
+
+impl Lexicon {
+    pub fn new(info: &ParserInfo) -> Lexicon {
+        let mut words: BTreeMap<String, Entry> = BTreeMap::new();
+        {
+            let mut add = |table: &HashMap<String, usize>, set: fn(&mut Entry, usize)| {
+                for (word, &value) in table {
+                    set(words.entry(fold(word).collect()).or_default(), value);
+                }
+            };
+
+            add(&info.jump, |entry, _| entry.jump = true);
+            add(&info.weekday, |entry, value| entry.weekday = Some(value));
+            add(&info.months, |entry, value| entry.month = Some(value));
+            add(&info.hms, |entry, value| entry.hms = Some(value));
+            add(&info.ymd, |entry, value| entry.ymd = Some(value));
+            add(&info.ampm, |entry, value| entry.ampm = Some(value));
+            add(&info.utczone, |entry, _| entry.utczone = true);
+            add(&info.pertain, |entry, _| entry.pertain = true);
+            add(&info.named_time, |entry, value| entry.named_time = Some(value));
+        }
+
+        for (word, &days) in &info.relative_day {
+            words.entry(fold(word).collect()).or_default().relative_day = Some(days);
+        }
+        for (word, &offset) in &info.tzoffset {
+            words.entry(fold(word).collect()).or_default().tzoffset = Some(offset);
+        }
+        for (name, era) in era_names() {
+            words.entry(fold(name).collect()).or_default().era = Some(era);
+        }
+        for &(marker, calendar) in ERA_MARKERS.iter() {
+            words.entry(fold(marker).collect()).or_default().calendar = Some(calendar);
+        }
+        for (name, &calendar) in &info.eras {
+            words.entry(fold(name).collect()).or_default().calendar = Some(calendar);
+        }
+
+        let mut lexicon = Lexicon {
+            nodes: vec![Node::default()],
+            words: words.len(),
+        };
+        for (word, entry) in words {
+            let mut node = 0;
+            for c in word.chars() {
+                node = lexicon.child(node, c);
+            }
+            lexicon.nodes[node].entry = Some(entry);
+        }
+        lexicon
+    }
+
+    /// Index of the child of `node` reached by `c`, added if missing.
+    fn child(&mut self, node: usize, c: char) -> usize {
+        match self.nodes[node].children.binary_search_by_key(&c, |&(c, _)| c) {
+            Ok(i) => self.nodes[node].children[i].1,
+            Err(i) => {
+                let child = self.nodes.len();
+                self.nodes.push(Node::default());
+                self.nodes[node].children.insert(i, (c, child));
+                child
+            }
+        }
+    }
+
+    /// Meanings of `word`, in any case.
+    pub fn get(&self, word: &str) -> Option<&Entry> {
+        let mut node = self.nodes.first()?;
+        for c in fold(word) {
+            let i = node.children.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+            node = &self.nodes[node.children[i].1];
+        }
+        node.entry.as_ref()
+    }
+}
+
+// The trie can hold to thousands of words with a CLDR locale, so leave it out
+impl fmt::Debug for Lexicon {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Lexicon({} words)", self.words)
+    }
+}
+
+/// `word` lowercased one character at a time. Unlike `str::to_lowercase`
+/// this can't tell a final sigma from any other, so both are folded to σ.
+fn fold(word: &str) -> impl Iterator<Item = char> + '_ {
+    word.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == 'ς' { 'σ' } else { c })
+}
+
+mod test {
+
+    use lexicon::Lexicon;
+    use ParserInfo;
+
+    #[test]
+    fn lookups() {
+        let lexicon = Lexicon::new(&ParserInfo::default());
+
+        let of = lexicon.get("OF").unwrap();
+        assert!(of.jump && of.pertain && of.is_vocabulary());
+        assert_eq!(lexicon.get("Sept").and_then(|entry| entry.month), Some(8));
+        let tonight = lexicon.get("Tonight").unwrap();
+        assert_eq!((tonight.relative_day, tonight.named_time), (Some(0), Some(21)));
+        assert_eq!(lexicon.get("septembre"), None);
+        // A prefix of a word isn't a word itself
+        assert_eq!(lexicon.get("septe"), None);
+        assert!(lexicon.get("HEISEI").and_then(|entry| entry.era).is_some());
+        assert_eq!(lexicon.get("民國").and_then(|entry| entry.calendar), Some(::Calendar::Minguo));
+        assert_eq!(lexicon.get(""), None);
+
+        let greek = ParserInfo::builder()
+            .tzoffset("EST", -5 * 3600)
+            .replace(::Vocabulary::Month, 1, &["Ιανουάριος"])
+            .build()
+            .unwrap();
+        let lexicon = Lexicon::new(&greek);
+        assert_eq!(lexicon.get("ΙΑΝΟΥΆΡΙΟΣ").and_then(|entry| entry.month), Some(0));
+        let est = lexicon.get("est").unwrap();
+        assert_eq!(est.tzoffset, Some(-5 * 3600));
+        assert!(!est.is_vocabulary());
+    }
+}
@@ -5,8 +5,11 @@
 extern crate lazy_static;
 
 extern crate chrono;
-extern crate num_traits;
-extern crate rust_decimal;
+#[cfg(feature = "log")]
+#[macro_use]
+extern crate log;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 use chrono::DateTime;
 use chrono::Datelike;
@@ -18,82 +21,130 @@ use chrono::NaiveDateTime;
 use chrono::NaiveTime;
 use chrono::Timelike;
 use chrono::Utc;
-use num_traits::cast::ToPrimitive;
-use rust_decimal::Decimal;
-use rust_decimal::Error as DecimalError;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::cmp::min;
-use std::num::ParseIntError;
-use std::str::FromStr;
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::RwLock;
 use std::vec::Vec;
 
+mod builder;
+mod business;
+mod calendar;
+mod cjk;
+mod clock;
+mod colloquial;
+mod era;
+mod error;
+mod holiday;
+mod iso;
+mod lexicon;
+mod locale;
+mod normalize;
+mod numbers;
+mod numeric;
+mod pivot;
+#[cfg(feature = "serde")]
+mod serialize;
+mod warning;
 mod weekday;
 
 #[cfg(test)]
 mod tests;
 
+use business::find_business_offset;
+use calendar::find_calendar_era;
+use calendar::find_calendar_month;
+use cjk::segment;
+use colloquial::find_clock_phrase;
+use era::find_era_year;
+use error::error_at;
+use iso::parse_iso;
+use iso::IsoTimestamp;
+use lexicon::Entry;
+use lexicon::Lexicon;
+use normalize::normalize_char;
+use numbers::is_ordinal_suffix;
+use numbers::replace_number_words;
+use numeric::Numeric;
+#[cfg(feature = "log")]
+use warning::log_warning;
 use weekday::day_of_week;
-use weekday::DayOfWeek;
+use weekday::nth_weekday_of_month;
+
+pub use builder::Conflict;
+pub use builder::InvalidVocabulary;
+pub use builder::Meaning;
+pub use builder::ParserInfoBuilder;
+pub use builder::Vocabulary;
+pub use business::BusinessCalendar;
+pub use calendar::Calendar;
+pub use clock::Clock;
+pub use clock::FixedClock;
+pub use clock::OffsetClock;
+pub use clock::SystemClock;
+pub use error::ErrorKind;
+pub use error::ParseError;
+pub use holiday::normalize_name;
+pub use holiday::HolidayCalendar;
+pub use holiday::HolidayRule;
+pub use holiday::HolidaySet;
+pub use pivot::YearPivot;
+pub use warning::Component;
+pub use warning::Warning;
+pub use weekday::DayOfWeek;
+
+/// Longest run of tokens (words, spaces and punctuation) considered when
+/// matching a multi-word holiday name.
+const MAX_HOLIDAY_TOKENS: usize = 12;
 
 lazy_static! {
-    static ref ZERO: Decimal = Decimal::new(0, 0);
-    static ref ONE: Decimal = Decimal::new(1, 0);
-    static ref TWENTY_FOUR: Decimal = Decimal::new(24, 0);
-    static ref SIXTY: Decimal = Decimal::new(60, 0);
+    // Shared by every `Parser::default()`; see `default_info`
+    static ref DEFAULT_INFO: RwLock<Arc<ParserInfo>> = RwLock::new(Arc::new(ParserInfo::default()));
 }
 
-#[derive(Debug, PartialEq)]
-pub enum ParseInternalError {
-    // Errors that indicate internal bugs
-    YMDEarlyResolve,
-    YMDValueUnset(Vec<YMDLabel>),
-    ParseIndexError,
-    InvalidDecimal,
-    InvalidInteger,
-
-    // Python-style errors
-    ValueError(String),
+type ParseResult<I> = Result<I, ParseError>;
+// Failures found before they can be pinned to a part of the input
+type ParseIResult<I> = Result<I, ErrorKind>;
+
+/// Splits a string into the words, numbers and separators the parser works
+/// with. Tokens borrow from the input unless their text had to be changed:
+/// digits and full-width forms folded to ASCII, whitespace to a single
+/// space, or a decimal comma to a point.
+pub struct Tokenizer<'a> {
+    input: &'a str,
+    // Byte offset of the first character not yet tokenized
+    pos: usize,
+    // Remaining pieces of a token that was split, last piece first
+    token_stack: Vec<Token<'a>>,
 }
 
-impl From<DecimalError> for ParseInternalError {
-    fn from(err: DecimalError) -> Self {
-        ParseInternalError::InvalidDecimal
-    }
+/// A token along with the byte range of the input it was read from.
+#[derive(Debug, Clone, PartialEq)]
+struct Token<'a> {
+    text: Cow<'a, str>,
+    span: Range<usize>,
 }
 
-impl From<ParseIntError> for ParseInternalError {
-    fn from(err: ParseIntError) -> Self {
-        ParseInternalError::InvalidInteger
+impl<'a> Token<'a> {
+    fn new<T: Into<Cow<'a, str>>>(text: T, span: Range<usize>) -> Self {
+        Token {
+            text: text.into(),
+            span,
+        }
     }
-}
 
-#[derive(Debug, PartialEq)]
-pub enum ParseError {
-    AmbiguousWeekday,
-    InternalError(ParseInternalError),
-    InvalidDay,
-    InvalidMonth,
-    UnrecognizedToken(String),
-    InvalidParseResult(ParsingResult),
-    AmPmWithoutHour,
-    InvalidHour,
-    TimezoneUnsupported,
-}
-
-impl From<ParseInternalError> for ParseError {
-    fn from(err: ParseInternalError) -> Self {
-        ParseError::InternalError(err)
+    /// The part of this token at byte `range` of its text, with `span`.
+    fn slice(&self, range: Range<usize>, span: Range<usize>) -> Token<'a> {
+        let text = match self.text {
+            Cow::Borrowed(text) => Cow::Borrowed(&text[range]),
+            Cow::Owned(ref text) => Cow::Owned(text[range].to_owned()),
+        };
+        Token { text, span }
     }
 }
 
-type ParseResult<I> = Result<I, ParseError>;
-type ParseIResult<I> = Result<I, ParseInternalError>;
-
-pub struct Tokenizer {
-    token_stack: Vec<String>,
-    parse_string: String,
-}
-
 #[derive(Debug, PartialEq)]
 enum ParseState {
     Empty,
@@ -103,245 +154,351 @@ enum ParseState {
     NumericDecimal,
 }
 
-impl Tokenizer {
-    fn new(parse_string: String) -> Self {
+impl<'a> Tokenizer<'a> {
+    fn new(input: &'a str) -> Self {
         Tokenizer {
+            input,
+            pos: 0,
             token_stack: Vec::new(),
-            parse_string: parse_string.chars().rev().collect(),
         }
     }
-}
 
-impl Iterator for Tokenizer {
-    type Item = String;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if !self.token_stack.is_empty() {
-            return Some(self.token_stack.pop().unwrap());
+    fn next_token(&mut self) -> Option<Token<'a>> {
+        if let Some(token) = self.token_stack.pop() {
+            return Some(token);
         };
-        if self.parse_string.is_empty() {
+        if self.pos == self.input.len() {
             return None;
         };
 
-        let mut char_stack: Vec<char> = Vec::new();
+        let start = self.pos;
+        let mut end = start;
+        let mut dot_count = 0;
+        let mut last = None;
+        // Whether the token's text is exactly the input it came from
+        let mut clean = true;
         let mut seen_letters = false;
         let mut state = ParseState::Empty;
 
-        while let Some(next) = self.parse_string.pop() {
-            match state {
+        for (chars, (i, c)) in self.input[start..].char_indices().enumerate() {
+            let next = normalize_char(c);
+            let mut done = false;
+
+            let take = match state {
                 ParseState::Empty => {
-                    if next.is_numeric() {
+                    if next.is_ascii_digit() {
                         state = ParseState::Numeric;
-                        char_stack.push(next);
                     } else if next.is_alphabetic() {
                         state = ParseState::Alpha;
                         seen_letters = true;
-                        char_stack.push(next);
-                    } else if next.is_whitespace() {
-                        char_stack.push(' ');
-                        break;
                     } else {
-                        char_stack.push(next);
-                        break;
+                        // Whitespace and punctuation are tokens of their own
+                        done = true;
                     }
+                    true
                 }
                 ParseState::Alpha => {
-                    if next.is_alphabetic() {
-                        char_stack.push(next);
-                    } else if next == '.' {
+                    if next == '.' {
                         state = ParseState::AlphaDecimal;
-                        char_stack.push(next);
-                    } else {
-                        // We don't recognize the character, so push it back
-                        // to be handled later.
-                        self.parse_string.push(next);
-                        break;
                     }
+                    next.is_alphabetic() || next == '.'
                 }
                 ParseState::AlphaDecimal => {
-                    if next == '.' || next.is_alphabetic() {
-                        char_stack.push(next);
-                    } else if next.is_numeric() && char_stack.last().unwrap().clone() == '.' {
-                        char_stack.push(next);
+                    if next.is_ascii_digit() && last == Some('.') {
                         state = ParseState::NumericDecimal;
+                        true
                     } else {
-                        self.parse_string.push(next);
-                        break;
+                        next == '.' || next.is_alphabetic()
                     }
                 }
                 ParseState::Numeric => {
-                    if next.is_numeric() {
-                        char_stack.push(next);
-                    } else if next == '.' || (next == ',' && char_stack.len() >= 2) {
-                        char_stack.push(next);
+                    if next == '.' || (next == ',' && chars >= 2) {
                         state = ParseState::NumericDecimal;
+                        true
                     } else {
-                        // We don't recognize the character, so push it back
-                        // to be handled later
-                        self.parse_string.push(next);
-                        break;
+                        next.is_ascii_digit()
                     }
                 }
                 ParseState::NumericDecimal => {
-                    if next == '.' || next.is_numeric() {
-                        char_stack.push(next);
-                    } else if next.is_alphabetic() && char_stack.last().unwrap().clone() == '.' {
-                        char_stack.push(next);
+                    if next.is_alphabetic() && last == Some('.') {
                         state = ParseState::AlphaDecimal;
+                        true
                     } else {
-                        self.parse_string.push(next);
-                        break;
+                        next == '.' || next.is_ascii_digit()
                     }
                 }
+            };
+
+            // A character that doesn't belong to this token starts the next
+            if !take {
+                break;
+            }
+
+            end = start + i + c.len_utf8();
+            if next == '.' {
+                dot_count += 1;
+            }
+            clean &= next == c;
+            last = Some(next);
+            if done {
+                break;
             }
         }
+        self.pos = end;
+
+        if state == ParseState::Empty && last.map_or(false, char::is_whitespace) {
+            return Some(Token::new(" ", start..end));
+        }
 
         // I like Python's version of this much better:
         // needs_split = seen_letters or char_stack.count('.') > 1 or char_stack[-1] in '.,'
-        let dot_count = char_stack.iter().fold(0, |count, character| {
-            count + (if character == &'.' { 1 } else { 0 })
-        });
-        let needs_split = seen_letters || dot_count > 1 || char_stack.last().unwrap() == &'.'
-            || char_stack.last().unwrap() == &',';
-        let final_string: String = char_stack.into_iter().collect();
-
-        let mut tokens = match state {
-            ParseState::Empty => vec![final_string],
-            ParseState::Alpha => vec![final_string],
-            ParseState::Numeric => vec![final_string],
-            ParseState::AlphaDecimal => {
-                if needs_split {
-                    decimal_split(&final_string, false)
-                } else {
-                    vec![final_string]
-                }
+        let needs_split = seen_letters || dot_count > 1 || last == Some('.') || last == Some(',');
+        let input = self.input;
+        let text = |span: Range<usize>| text_of(input, span, clean);
+
+        let mut token = match state {
+            ParseState::AlphaDecimal | ParseState::NumericDecimal if needs_split => {
+                let cast_period = state == ParseState::NumericDecimal && dot_count == 0;
+                decimal_split(input, start..end, cast_period, clean, &mut self.token_stack);
+                // The stack is empty here, and pops from the end
+                self.token_stack.reverse();
+                self.token_stack.pop()?
             }
-            ParseState::NumericDecimal => {
-                if needs_split {
-                    decimal_split(&final_string, dot_count == 0)
-                } else {
-                    vec![final_string]
-                }
-            }
-        }.into_iter()
-            .rev()
-            .collect();
-
-        self.token_stack.append(&mut tokens);
-        // UNWRAP: Previous match guaranteed that at least one token was added
-        let token = self.token_stack.pop().unwrap();
-        if state == ParseState::NumericDecimal && !token.contains(".") {
-            Some(token.replace(",", "."))
-        } else {
-            Some(token)
+            _ => Token::new(text(start..end), start..end),
+        };
+        if state == ParseState::NumericDecimal && !token.text.contains('.') {
+            token.text = Cow::Owned(token.text.replace(",", "."));
         }
+        Some(token)
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token().map(|token| token.text)
+    }
+}
+
+/// Text of the input at `span`, borrowed if it is `clean` (needs no
+/// normalizing).
+fn text_of<'a>(input: &'a str, span: Range<usize>, clean: bool) -> Cow<'a, str> {
+    if clean {
+        Cow::Borrowed(&input[span])
+    } else {
+        Cow::Owned(input[span].chars().map(normalize_char).collect())
     }
 }
 
-fn decimal_split(characters: &str, cast_period: bool) -> Vec<String> {
-    let mut token_stack: Vec<String> = Vec::new();
-    let mut char_stack: Vec<char> = Vec::new();
+/// Split the token at `span` of `input` into runs of letters or digits and
+/// the separators between them, pushing the pieces onto `tokens` in order.
+/// With `cast_period`, separators become periods.
+fn decimal_split<'a>(
+    input: &'a str,
+    span: Range<usize>,
+    cast_period: bool,
+    clean: bool,
+    tokens: &mut Vec<Token<'a>>,
+) {
+    let mut run_start = span.start;
     let mut state = ParseState::Empty;
 
-    for c in characters.chars() {
-        match state {
-            ParseState::Empty => {
-                if c.is_alphabetic() {
-                    char_stack.push(c);
-                    state = ParseState::Alpha;
-                } else if c.is_numeric() {
-                    char_stack.push(c);
-                    state = ParseState::Numeric;
-                } else {
-                    let character = if cast_period { '.' } else { c };
-                    token_stack.push(character.to_string());
-                }
-            }
-            ParseState::Alpha => {
-                if c.is_alphabetic() {
-                    char_stack.push(c);
-                } else {
-                    token_stack.push(char_stack.iter().collect());
-                    char_stack.clear();
-                    let character = if cast_period { '.' } else { c };
-                    token_stack.push(character.to_string());
-                    state = ParseState::Empty;
-                }
-            }
-            ParseState::Numeric => {
-                if c.is_numeric() {
-                    char_stack.push(c);
-                } else {
-                    token_stack.push(char_stack.iter().collect());
-                    char_stack.clear();
-                    let character = if cast_period { '.' } else { c };
-                    token_stack.push(character.to_string());
-                    state = ParseState::Empty;
-                }
-            }
-            _ => panic!("Invalid parse state during decimal_split()"),
+    for (i, c) in input[span.clone()].char_indices() {
+        let at = span.start + i;
+        let next = normalize_char(c);
+
+        // Runs are only ever letters or digits here, so the decimal states
+        // are treated like their plain counterparts
+        let in_run = match state {
+            ParseState::Empty => false,
+            ParseState::Alpha | ParseState::AlphaDecimal => next.is_alphabetic(),
+            ParseState::Numeric | ParseState::NumericDecimal => next.is_ascii_digit(),
+        };
+        if in_run {
+            continue;
+        }
+
+        if state != ParseState::Empty {
+            tokens.push(Token::new(text_of(input, run_start..at, clean), run_start..at));
+            state = ParseState::Empty;
+        } else if next.is_alphabetic() {
+            run_start = at;
+            state = ParseState::Alpha;
+            continue;
+        } else if next.is_ascii_digit() {
+            run_start = at;
+            state = ParseState::Numeric;
+            continue;
         }
-    }
 
-    match state {
-        ParseState::Alpha => token_stack.push(char_stack.iter().collect()),
-        ParseState::Numeric => token_stack.push(char_stack.iter().collect()),
-        ParseState::Empty => (),
-        _ => panic!("Invalid parse state during decimal_split()"),
+        let separator = at..at + c.len_utf8();
+        let text = if cast_period { Cow::Borrowed(".") } else { text_of(input, separator.clone(), clean) };
+        tokens.push(Token::new(text, separator));
     }
 
-    token_stack
+    if state != ParseState::Empty {
+        tokens.push(Token::new(text_of(input, run_start..span.end, clean), run_start..span.end));
+    }
 }
 
 pub fn tokenize(parse_string: &str) -> Vec<String> {
-    let tokenizer = Tokenizer::new(parse_string.to_owned());
-    tokenizer.collect()
+    Tokenizer::new(parse_string).map(Cow::into_owned).collect()
+}
+
+/// Tokens of `parse_string` with their positions in it.
+fn tokenize_spans<'a>(parse_string: &'a str) -> Vec<Token<'a>> {
+    let mut tokenizer = Tokenizer::new(parse_string);
+    let mut tokens = Vec::new();
+    while let Some(token) = tokenizer.next_token() {
+        tokens.push(token);
+    }
+    tokens
 }
 
 fn parse_info(vec: Vec<Vec<&str>>) -> HashMap<String, usize> {
     let mut m = HashMap::new();
 
-    if vec.len() == 1 {
-        for (i, val) in vec.get(0).unwrap().into_iter().enumerate() {
+    // A single list is a set of words (jump, pertain); its values are unused
+    for (i, val_vec) in vec.into_iter().enumerate() {
+        for val in val_vec.into_iter() {
             m.insert(val.to_lowercase(), i);
         }
-    } else {
-        for (i, val_vec) in vec.into_iter().enumerate() {
-            for val in val_vec.into_iter() {
-                m.insert(val.to_lowercase(), i);
-            }
-        }
     }
 
     m
 }
 
-#[derive(Debug, PartialEq)]
+/// Relative day words, starting from yesterday: the first list is an offset
+/// of -1 days, the second 0 and the third +1.
+fn parse_relative_days(vec: Vec<Vec<&str>>) -> HashMap<String, i64> {
+    parse_info(vec)
+        .into_iter()
+        .map(|(name, i)| (name, i as i64 - 1))
+        .collect()
+}
+
+fn parse_hours(vec: Vec<(&str, usize)>) -> HashMap<String, usize> {
+    vec.into_iter()
+        .map(|(name, hour)| (name.to_lowercase(), hour))
+        .collect()
+}
+
+/// Words and defaults the parser works with.
+///
+/// With the `serde` feature, a `ParserInfo` can be loaded from and saved to
+/// any serde format. Every field is optional and defaults to the built-in
+/// English value; words are case-insensitive. In TOML:
+///
+/// ```toml
+/// dayfirst = false
+/// yearfirst = false
+/// # Reference year two-digit years are expanded around; only saved if set
+/// # with `reference_year`, and otherwise the current year
+/// year = 2018
+/// # "SlidingWindow" (within 50 years of `year`), "PastOnly", "FutureOnly",
+/// # "Reject" or { FixedPivot = N }
+/// year_pivot = "SlidingWindow"
+/// # "Gregorian", "Buddhist", "Minguo", "Persian", "Hijri", "Hebrew" or
+/// # { YearOffset = N }
+/// calendar = "Gregorian"
+/// # AM/PM words may also come before the hour
+/// ampm_prefix = false
+/// # Words skipped over between date components
+/// jump = [" ", ".", ",", "at", "of"]
+/// # Seven lists, Monday first
+/// weekday = [["mon", "monday"], ["tue", "tuesday"], ...]
+/// # Twelve lists, January first
+/// months = [["jan", "january"], ["feb", "february"], ...]
+/// # Hours, minutes, seconds
+/// hms = [["h", "hour", "hours"], ["m", "minute", "minutes"], ["s", "second", "seconds"]]
+/// # Years, months, days: units following a number, like 年 in 2003年
+/// ymd = [[], [], []]
+/// # AM, PM
+/// ampm = [["am", "a"], ["pm", "p"]]
+/// utczone = ["utc", "gmt", "z"]
+/// pertain = ["of"]
+///
+/// # Hour of the day
+/// [named_time]
+/// noon = 12
+///
+/// # Offset in days from the default date
+/// [relative_day]
+/// tomorrow = 1
+///
+/// # Seconds east of UTC
+/// [tzoffset]
+/// est = -18000
+///
+/// # Calendar years marked with an era name are in, compared by the
+/// # name's letters alone
+/// [eras]
+/// ad = "Gregorian"
+/// ```
+///
+/// The clock isn't serialized; a deserialized info uses the system clock.
+/// Nor is it compared by `==`, which only sees the reference year it gave.
+#[derive(Debug, Clone)]
 pub struct ParserInfo {
     jump: HashMap<String, usize>,
     weekday: HashMap<String, usize>,
     months: HashMap<String, usize>,
     hms: HashMap<String, usize>,
+    // Markers following a year, month or day number, e.g. 年, 月 and 日
+    ymd: HashMap<String, usize>,
     ampm: HashMap<String, usize>,
     utczone: HashMap<String, usize>,
     pertain: HashMap<String, usize>,
-    tzoffset: HashMap<String, usize>,
+    // Offsets in seconds east of UTC
+    tzoffset: HashMap<String, i32>,
+    named_time: HashMap<String, usize>,
+    relative_day: HashMap<String, i64>,
+    // Era names, keyed by `era_key`, and the calendar their years are in
+    eras: HashMap<String, Calendar>,
+    // AM/PM may come before the hour, as in 午後3時
+    ampm_prefix: bool,
+    calendar: Calendar,
     dayfirst: bool,
     yearfirst: bool,
     year: i32,
     century: i32,
+    // Whether `year` was set with `reference_year` rather than by the clock
+    year_overridden: bool,
+    year_pivot: YearPivot,
+    clock: Arc<dyn Clock>,
+    // The tables above compiled for lookups; see `compiled`
+    lexicon: Arc<Lexicon>,
+}
+
+// Clocks can't be compared, so the clock is left out; what it decided when
+// it was set, the reference year, is compared
+impl PartialEq for ParserInfo {
+    fn eq(&self, other: &ParserInfo) -> bool {
+        self.jump == other.jump && self.weekday == other.weekday && self.months == other.months
+            && self.hms == other.hms && self.ymd == other.ymd && self.ampm == other.ampm
+            && self.utczone == other.utczone && self.pertain == other.pertain
+            && self.tzoffset == other.tzoffset && self.named_time == other.named_time
+            && self.relative_day == other.relative_day && self.eras == other.eras
+            && self.ampm_prefix == other.ampm_prefix
+            && self.calendar == other.calendar && self.dayfirst == other.dayfirst
+            && self.yearfirst == other.yearfirst && self.year == other.year
+            && self.century == other.century && self.year_overridden == other.year_overridden
+            && self.year_pivot == other.year_pivot
+    }
 }
 
 impl Default for ParserInfo {
     fn default() -> Self {
-        let year = Local::now().year();
+        let clock = SystemClock;
+        let year = clock.now().year();
         let century = year / 100 * 100;
 
         ParserInfo {
             jump: parse_info(vec![
                 vec![
                     " ", ".", ",", ";", "-", "/", "'", "at", "on", "and", "ad", "m", "t", "of",
-                    "st", "nd", "rd", "th",
+                    "st", "nd", "rd", "th", "the", "in", "this",
                 ],
             ]),
             weekday: parse_info(vec![
@@ -372,96 +529,250 @@ impl Default for ParserInfo {
                 vec!["m", "minute", "minutes"],
                 vec!["s", "second", "seconds"],
             ]),
+            ymd: parse_info(vec![vec![], vec![], vec![]]),
             ampm: parse_info(vec![vec!["am", "a"], vec!["pm", "p"]]),
             utczone: parse_info(vec![vec!["UTC", "GMT", "Z"]]),
             pertain: parse_info(vec![vec!["of"]]),
-            tzoffset: parse_info(vec![vec![]]),
+            tzoffset: HashMap::new(),
+            named_time: parse_hours(vec![
+                ("midnight", 0),
+                ("morning", 9),
+                ("noon", 12),
+                ("midday", 12),
+                ("afternoon", 15),
+                ("evening", 18),
+                ("night", 21),
+                ("tonight", 21),
+            ]),
+            relative_day: parse_relative_days(vec![
+                vec!["yesterday"],
+                vec!["today", "tonight"],
+                vec!["tomorrow"],
+            ]),
+            eras: HashMap::new(),
+            ampm_prefix: false,
+            calendar: Calendar::Gregorian,
             dayfirst: false,
             yearfirst: false,
-            year: year,
-            century: century,
+            year,
+            century,
+            year_overridden: false,
+            year_pivot: YearPivot::SlidingWindow,
+            clock: Arc::new(clock),
+            lexicon: Arc::default(),
         }
+        .compiled()
     }
 }
 
 impl ParserInfo {
+    /// Vocabulary for a language other than English, selected by a language
+    /// tag such as `"fr"` or `"de-AT"`. Supported languages are English,
+    /// French, German, Spanish, Italian, Portuguese, Dutch, Japanese and
+    /// Chinese. The European languages default to `dayfirst`, Japanese and
+    /// Chinese to `yearfirst`.
+    pub fn for_locale(tag: &str) -> Option<ParserInfo> {
+        locale::parser_info(tag)
+    }
+
+    /// Vocabulary generated from CLDR data (months in format and stand-alone
+    /// forms, weekdays, day periods, common era names and date order) for
+    /// one of the CLDR locales vendored in `cldr/`: German, English,
+    /// Spanish, French and Japanese. See `build_cldr.py`.
+    #[cfg(feature = "cldr")]
+    pub fn from_cldr(tag: &str) -> Option<ParserInfo> {
+        locale::cldr::parser_info(tag)
+    }
+
+    /// Language tags usable with `from_cldr`.
+    #[cfg(feature = "cldr")]
+    pub fn cldr_locales() -> Vec<&'static str> {
+        locale::cldr::tags()
+    }
+
+    /// Combine several infos, e.g. for input that may be in any of a few
+    /// languages. Where infos give a word different meanings the earliest
+    /// one wins, and `dayfirst`, `yearfirst` and the
+    /// reference year come from the first info. See `ParserInfoBuilder::merge`
+    /// for the details, and `conflicts` to list the words affected. Fails if
+    /// any info gives a word a value outside the range of its category.
+    pub fn compose(infos: &[ParserInfo]) -> Result<ParserInfo, InvalidVocabulary> {
+        ParserInfoBuilder::compose(infos).build()
+    }
+
+    /// Words `compose` would drop a meaning of, because an earlier info in
+    /// `infos` gives them a different one.
+    pub fn conflicts(infos: &[ParserInfo]) -> Vec<Conflict> {
+        ParserInfoBuilder::compose(infos).conflicts().to_vec()
+    }
+
+    /// `compose` the infos for several language tags, in order of precedence.
+    /// Returns `None` if any tag isn't supported.
+    pub fn for_locales(tags: &[&str]) -> Option<Result<ParserInfo, InvalidVocabulary>> {
+        let infos: Option<Vec<ParserInfo>> = tags.iter().map(|tag| locale::parser_info(tag)).collect();
+        infos.map(|infos| ParserInfo::compose(&infos))
+    }
+
+    /// Start a [`ParserInfoBuilder`](struct.ParserInfoBuilder.html) from
+    /// the default English vocabulary.
+    pub fn builder() -> ParserInfoBuilder {
+        ParserInfoBuilder::new()
+    }
+
+    /// Take the current time from `clock`, and the reference year for
+    /// two-digit years from it as of now.
+    pub fn set_clock<C: Clock + 'static>(&mut self, clock: C) {
+        self.year = clock.now().year();
+        self.century = self.year / 100 * 100;
+        self.year_overridden = false;
+        self.clock = Arc::new(clock);
+    }
+
+    /// Set the hour a named time of day ("noon", "evening") stands for.
+    /// Names mapping to 12 or later also mark an explicit hour as PM, so
+    /// "7 in the evening" is 19:00. Fails, leaving the info unchanged, if
+    /// `hour` is past 23.
+    pub fn set_named_time(&mut self, name: &str, hour: u32) -> Result<(), InvalidVocabulary> {
+        if hour > 23 {
+            return Err(InvalidVocabulary {
+                category: Vocabulary::NamedTime,
+                value: i64::from(hour),
+            });
+        }
+
+        self.named_time.insert(name.to_lowercase(), hour as usize);
+        self.lexicon = Arc::new(Lexicon::new(self));
+        Ok(())
+    }
+
+    /// This info with its lexicon compiled from the tables. Whatever builds
+    /// or changes a `ParserInfo` finishes with this, since lookups only go
+    /// through the lexicon.
+    fn compiled(mut self) -> ParserInfo {
+        self.lexicon = Arc::new(Lexicon::new(&self));
+        self
+    }
+
+    /// Everything `name` means, in any case.
+    fn word(&self, name: &str) -> Entry {
+        self.lexicon.get(name).cloned().unwrap_or_default()
+    }
+
     fn get_jump(&self, name: &str) -> bool {
-        self.jump.contains_key(&name.to_lowercase())
+        self.word(name).jump
     }
 
     fn get_weekday(&self, name: &str) -> Option<usize> {
-        self.weekday.get(&name.to_lowercase()).map(|i| *i)
+        self.word(name).weekday
     }
 
     fn get_month(&self, name: &str) -> Option<usize> {
-        self.months.get(&name.to_lowercase()).map(|u| u + 1)
+        self.word(name).month.map(|u| u + 1)
     }
 
     fn get_hms(&self, name: &str) -> Option<usize> {
-        self.hms.get(&name.to_lowercase()).map(|i| *i)
+        self.word(name).hms
     }
 
-    fn get_ampm(&self, name: &str) -> Option<bool> {
-        if let Some(v) = self.ampm.get(&name.to_lowercase()) {
-            Some(v.to_owned() == 1)
-        } else {
-            None
+    fn get_ymd(&self, name: &str) -> Option<YMDLabel> {
+        match self.word(name).ymd {
+            Some(0) => Some(YMDLabel::Year),
+            Some(1) => Some(YMDLabel::Month),
+            Some(2) => Some(YMDLabel::Day),
+            _ => None,
         }
     }
 
+    /// Whether `name` is in any of the vocabulary tables or names an era.
+    fn knows(&self, name: &str) -> bool {
+        self.word(name).is_vocabulary() || name == era::FIRST_YEAR
+    }
+
+    fn get_ampm(&self, name: &str) -> Option<bool> {
+        self.word(name).ampm.map(|v| v == 1)
+    }
+
+    fn get_named_time(&self, name: &str) -> Option<usize> {
+        self.word(name).named_time
+    }
+
+    fn get_relative_day(&self, name: &str) -> Option<i64> {
+        self.word(name).relative_day
+    }
+
     fn get_pertain(&self, name: &str) -> bool {
-        self.pertain.contains_key(&name.to_lowercase())
+        self.word(name).pertain
     }
 
     fn get_utczone(&self, name: &str) -> bool {
-        self.utczone.contains_key(&name.to_lowercase())
+        self.word(name).utczone
     }
 
-    fn get_tzoffset(&self, name: &str) -> Option<usize> {
-        if self.utczone.contains_key(&name.to_lowercase()) {
+    fn get_tzoffset(&self, name: &str) -> Option<i32> {
+        let word = self.word(name);
+        if word.utczone {
             Some(0)
         } else {
-            self.tzoffset.get(&name.to_lowercase()).cloned()
+            word.tzoffset
         }
     }
 
-    fn convertyear(&self, year: i32, century_specified: bool) -> i32 {
-        let mut year = year;
+    fn convertyear(&self, year: i32, century_specified: bool) -> ParseIResult<i32> {
+        self.convertyear_in(year, century_specified, self.calendar)
+    }
 
-        if year < 100 && !century_specified {
-            year += self.century;
-            if year >= self.year + 50 {
-                year -= 100;
-            } else if year < self.year - 50 {
-                year += 100
-            }
+    fn convertyear_in(&self, year: i32, century_specified: bool, calendar: Calendar) -> ParseIResult<i32> {
+        if calendar != Calendar::Gregorian {
+            year.checked_add(calendar.year_offset()).ok_or(ErrorKind::InvalidYear)
+        } else if year < 100 && !century_specified {
+            self.year_pivot.expand(year, self.year).ok_or(ErrorKind::InvalidYear)
+        } else {
+            Ok(year)
         }
-
-        year
     }
 
     // TODO: Should this be moved elsewhere?
-    fn validate(&self, res: &mut ParsingResult) -> bool {
+    fn validate(&self, res: &mut ParsingResult) -> ParseIResult<()> {
         if let Some(y) = res.year {
-            res.year = Some(self.convertyear(y, res.century_specified))
+            let calendar = res.calendar.unwrap_or(self.calendar);
+            let year = self.convertyear_in(y, res.century_specified, calendar)?;
+            if calendar == Calendar::Gregorian && year != y {
+                if let Some(span) = res.spans.year.clone() {
+                    res.warnings.push(Warning::YearPivoted {
+                        from: y,
+                        to: year,
+                        span,
+                        pivot: self.year_pivot,
+                    });
+                }
+            }
+            res.year = Some(year)
         };
 
+        if let (Some(era), Some(year)) = (res.era, res.year) {
+            let month = res.month.map(|m| m as u32);
+            let day = res.day.map(|d| d as u32);
+            if !era::contains(era, year, month, day) {
+                return Err(ErrorKind::InvalidYear);
+            }
+        }
+
         if res.tzoffset == Some(0) && res.tzname.is_none() || res.tzname == Some("Z".to_owned()) {
             res.tzname = Some("UTC".to_owned());
             res.tzoffset = Some(0);
-        } else if res.tzoffset != Some(0) && res.tzname.is_some()
-            && self.get_utczone(res.tzname.as_ref().unwrap())
+        } else if res.tzoffset != Some(0)
+            && res.tzname.as_ref().map_or(false, |name| self.get_utczone(name))
         {
             res.tzoffset = Some(0);
         }
 
-        true
+        Ok(())
     }
 }
 
-fn days_in_month(year: i32, month: i32) -> Result<u32, ParseError> {
+fn days_in_month(year: i32, month: i32) -> ParseIResult<u32> {
     let leap_year = match year % 4 {
-        0 => year % 400 != 0,
+        0 => year % 100 != 0 || year % 400 == 0,
         _ => false,
     };
 
@@ -474,21 +785,43 @@ fn days_in_month(year: i32, month: i32) -> Result<u32, ParseError> {
         1 | 3 | 5 | 7 | 8 | 10 | 12 => Ok(31),
         4 | 6 | 9 | 11 => Ok(30),
         _ => {
-            Err(ParseError::InvalidMonth)
+            Err(ErrorKind::InvalidMonth)
         }
     }
 }
 
+/// The integer at `tokens[idx]`, for tokens a date component must follow.
+fn integer_at(tokens: &[&str], idx: usize) -> ParseIResult<i32> {
+    match tokens.get(idx) {
+        Some(token) => parse_int(token),
+        None => Err(ErrorKind::UnexpectedEnd),
+    }
+}
+
+fn parse_int(token: &str) -> ParseIResult<i32> {
+    token.parse::<i32>().map_err(|_| ErrorKind::InvalidNumber)
+}
+
+/// Hours or minutes of a numeric UTC offset.
+fn parse_offset(token: &str) -> ParseIResult<i32> {
+    match token.parse::<u16>() {
+        Ok(value) if token.chars().all(|c| c.is_ascii_digit()) => Ok(value as i32),
+        _ => Err(ErrorKind::UnrecognizedToken),
+    }
+}
+
 #[derive(Debug, Hash, PartialEq, Eq)]
-pub enum YMDLabel {
+enum YMDLabel {
     Year,
     Month,
     Day,
 }
 
 #[derive(Debug, Default)]
+#[allow(clippy::upper_case_acronyms)]
 struct YMD {
     _ymd: Vec<i32>, // TODO: This seems like a super weird way to store things
+    spans: Vec<Range<usize>>,
     century_specified: bool,
     dstridx: Option<usize>,
     mstridx: Option<usize>,
@@ -506,37 +839,39 @@ impl YMD {
     }
 
     fn could_be_day(&self, val: i32) -> bool {
-        if self.dstridx.is_some() {
-            false
-        } else if self.mstridx.is_none() {
-            (1 <= val) && (val <= 31)
-        } else if self.ystridx.is_none() {
-            // UNWRAP: Earlier condition catches mstridx missing
-            let month = self._ymd[self.mstridx.unwrap()];
-            1 <= val && (val <= days_in_month(2000, month).unwrap() as i32)
-        } else {
-            // UNWRAP: Earlier conditions prevent us from unsafely unwrapping
-            let month = self._ymd[self.mstridx.unwrap()];
-            let year = self._ymd[self.ystridx.unwrap()];
-            1 <= val && (val <= days_in_month(year, month).unwrap() as i32)
+        let month = self.mstridx.and_then(|i| self._ymd.get(i));
+        let year = self.ystridx.and_then(|i| self._ymd.get(i));
+
+        // Months of other calendars can run to 13 and 31 days
+        match (self.dstridx, month, year) {
+            (Some(_), _, _) => false,
+            (None, None, _) => (1..=31).contains(&val),
+            (None, Some(&month), None) => {
+                1 <= val && (val <= days_in_month(2000, month).unwrap_or(31) as i32)
+            }
+            (None, Some(&month), Some(&year)) => {
+                1 <= val && (val <= days_in_month(year, month).unwrap_or(31) as i32)
+            }
         }
     }
 
-    fn append(&mut self, val: i32, token: &str, label: Option<YMDLabel>) -> ParseIResult<()> {
+    fn append(
+        &mut self,
+        val: i32,
+        token: &str,
+        span: Range<usize>,
+        label: Option<YMDLabel>,
+    ) -> ParseIResult<()> {
         let mut label = label;
 
         // Python auto-detects strings using the '__len__' function here.
         // We instead take in both and handle as necessary.
-        if Decimal::from_str(token).is_ok() && token.len() > 2 {
+        if Numeric::parse(token).is_some() && token.len() > 2 {
             self.century_specified = true;
             match label {
                 None | Some(YMDLabel::Year) => label = Some(YMDLabel::Year),
                 _ => {
-                    return Err(ParseInternalError::ValueError(format!(
-                        "Invalid label {:?} for token {:?}",
-                        label,
-                        token
-                    )))
+                    return Err(ErrorKind::AmbiguousDate)
                 }
             }
         }
@@ -547,23 +882,18 @@ impl YMD {
                 None => label = Some(YMDLabel::Year),
                 Some(YMDLabel::Year) => (),
                 _ => {
-                    return Err(ParseInternalError::ValueError(format!(
-                        "Invalid label {:?} for token {:?}",
-                        label,
-                        token
-                    )))
+                    return Err(ErrorKind::AmbiguousDate)
                 }
             }
         }
 
         self._ymd.push(val);
+        self.spans.push(span);
 
         match label {
             Some(YMDLabel::Month) => {
                 if self.mstridx.is_some() {
-                    Err(ParseInternalError::ValueError(
-                        "Month already set.".to_owned(),
-                    ))
+                    Err(ErrorKind::AmbiguousDate)
                 } else {
                     self.mstridx = Some(self._ymd.len() - 1);
                     Ok(())
@@ -571,9 +901,7 @@ impl YMD {
             }
             Some(YMDLabel::Day) => {
                 if self.dstridx.is_some() {
-                    Err(ParseInternalError::ValueError(
-                        "Day already set.".to_owned(),
-                    ))
+                    Err(ErrorKind::AmbiguousDate)
                 } else {
                     self.dstridx = Some(self._ymd.len() - 1);
                     Ok(())
@@ -581,22 +909,20 @@ impl YMD {
             }
             Some(YMDLabel::Year) => {
                 if self.ystridx.is_some() {
-                    Err(ParseInternalError::ValueError(
-                        "Year already set.".to_owned(),
-                    ))
+                    Err(ErrorKind::AmbiguousDate)
                 } else {
                     self.ystridx = Some(self._ymd.len() - 1);
                     Ok(())
                 }
             }
-            None => Err(ParseInternalError::ValueError("Missing label.".to_owned())),
+            None => Ok(()),
         }
     }
 
     fn resolve_from_stridxs(
         &mut self,
         strids: &mut HashMap<YMDLabel, usize>,
-    ) -> ParseIResult<(Option<i32>, Option<i32>, Option<i32>)> {
+    ) -> ParseIResult<(Option<usize>, Option<usize>, Option<usize>)> {
         if self._ymd.len() == 3 && strids.len() == 2 {
             let missing_key = if !strids.contains_key(&YMDLabel::Year) {
                 YMDLabel::Year
@@ -606,7 +932,7 @@ impl YMD {
                 YMDLabel::Day
             };
 
-            let strids_vals: Vec<usize> = strids.values().map(|u| u.clone()).collect();
+            let strids_vals: Vec<usize> = strids.values().copied().collect();
             let missing_val = if !strids_vals.contains(&0) {
                 0
             } else if !strids_vals.contains(&1) {
@@ -619,114 +945,109 @@ impl YMD {
         }
 
         if self._ymd.len() != strids.len() {
-            return Err(ParseInternalError::YMDEarlyResolve);
+            return Err(ErrorKind::AmbiguousDate);
         }
 
         Ok((
             strids
                 .get(&YMDLabel::Year)
-                .map(|i| self._ymd[*i]),
+                .cloned(),
             strids
                 .get(&YMDLabel::Month)
-                .map(|i| self._ymd[*i]),
+                .cloned(),
             strids
                 .get(&YMDLabel::Day)
-                .map(|i| self._ymd[*i]),
+                .cloned(),
         ))
     }
 
+    /// Indices of the year, month and day among the values read so far.
     fn resolve_ymd(
         &mut self,
         yearfirst: bool,
         dayfirst: bool,
-    ) -> ParseIResult<(Option<i32>, Option<i32>, Option<i32>)> {
+    ) -> ParseIResult<(Option<usize>, Option<usize>, Option<usize>)> {
         let len_ymd = self._ymd.len();
 
         let mut strids: HashMap<YMDLabel, usize> = HashMap::new();
         self.ystridx
-            .map(|u| strids.insert(YMDLabel::Year, u.clone()));
+            .map(|u| strids.insert(YMDLabel::Year, u));
         self.mstridx
-            .map(|u| strids.insert(YMDLabel::Month, u.clone()));
+            .map(|u| strids.insert(YMDLabel::Month, u));
         self.dstridx
-            .map(|u| strids.insert(YMDLabel::Day, u.clone()));
+            .map(|u| strids.insert(YMDLabel::Day, u));
 
         // TODO: More Rustiomatic way of doing this?
-        if len_ymd == strids.len() && strids.len() > 0
+        if len_ymd == strids.len() && !strids.is_empty()
             || (len_ymd == 3 && strids.len() == 2)
         {
             return self.resolve_from_stridxs(&mut strids);
         };
 
         if len_ymd > 3 {
-            return Err(ParseInternalError::ValueError(
-                "More than three YMD values".to_owned(),
-            ));
+            return Err(ErrorKind::AmbiguousDate);
         }
 
         match (len_ymd, self.mstridx) {
             (1, Some(val)) |
             (2, Some(val)) => {
-                let other = if len_ymd == 1 {
-                    self._ymd[0]
-                } else {
-                    self._ymd[1 - val]
-                };
-                if other > 31 {
-                    return Ok((Some(other), Some(self._ymd[val]), None));
+                let other = if len_ymd == 1 { 0 } else { 1 - val };
+                if self._ymd[other] > 31 {
+                    return Ok((Some(other), Some(val), None));
                 }
-                return Ok((None, Some(self._ymd[val]), Some(other)));
+                Ok((None, Some(val), Some(other)))
             },
             (2, None) => {
                 if self._ymd[0] > 31 {
-                    return Ok((Some(self._ymd[0]), Some(self._ymd[1]), None));
+                    return Ok((Some(0), Some(1), None));
                 }
                 if self._ymd[1] > 31 {
-                    return Ok((Some(self._ymd[1]), Some(self._ymd[0]), None));
+                    return Ok((Some(1), Some(0), None));
                 }
                 if dayfirst && self._ymd[1] <= 12 {
-                    return Ok((None, Some(self._ymd[1]), Some(self._ymd[0])));
+                    return Ok((None, Some(1), Some(0)));
                 }
-                return Ok((None, Some(self._ymd[0]), Some(self._ymd[1])));
+                Ok((None, Some(0), Some(1)))
             },
             (3, Some(0)) => {
                 if self._ymd[1] > 31 {
-                    return Ok((Some(self._ymd[1]), Some(self._ymd[0]), Some(self._ymd[2])));
+                    return Ok((Some(1), Some(0), Some(2)));
                 }
-                return Ok((Some(self._ymd[2]), Some(self._ymd[0]), Some(self._ymd[1])));
+                Ok((Some(2), Some(0), Some(1)))
             },
             (3, Some(1)) => {
                 if self._ymd[0] > 31 || (yearfirst && self._ymd[2] <= 31) {
-                    return Ok((Some(self._ymd[0]), Some(self._ymd[1]), Some(self._ymd[2])));
+                    return Ok((Some(0), Some(1), Some(2)));
                 }
-                return Ok((Some(self._ymd[2]), Some(self._ymd[1]), Some(self._ymd[0])));
+                Ok((Some(2), Some(1), Some(0)))
             },
             (3, Some(2)) => {
                 // It was in the original docs, so: WTF!?
                 if self._ymd[1] > 31 {
-                    return Ok((Some(self._ymd[2]), Some(self._ymd[1]), Some(self._ymd[0])));
+                    return Ok((Some(2), Some(1), Some(0)));
                 }
-                return Ok((Some(self._ymd[0]), Some(self._ymd[2]), Some(self._ymd[1])));
+                Ok((Some(0), Some(2), Some(1)))
             },
             (3, None) => {
                 if self._ymd[0] > 31 || self.ystridx == Some(0)
                     || (yearfirst && self._ymd[1] <= 12 && self._ymd[2] <= 31)
                 {
                     if dayfirst && self._ymd[2] <= 12 {
-                        return Ok((Some(self._ymd[0]), Some(self._ymd[2]), Some(self._ymd[1])));
+                        return Ok((Some(0), Some(2), Some(1)));
                     }
-                    return Ok((Some(self._ymd[0]), Some(self._ymd[1]), Some(self._ymd[2])));
+                    return Ok((Some(0), Some(1), Some(2)));
                 } else if self._ymd[0] > 12 || (dayfirst && self._ymd[1] <= 12) {
-                    return Ok((Some(self._ymd[2]), Some(self._ymd[1]), Some(self._ymd[0])));
+                    return Ok((Some(2), Some(1), Some(0)));
                 }
-                return Ok((Some(self._ymd[2]), Some(self._ymd[0]), Some(self._ymd[1])));
+                Ok((Some(2), Some(0), Some(1)))
             },
-            (_, _) => { return Ok((None, None, None)); },
+            (_, _) => Ok((None, None, None)),
         }
     }
 }
 
 #[derive(Default, Debug, PartialEq)]
-pub struct ParsingResult {
+struct ParsingResult {
     year: Option<i32>,
     month: Option<i32>,
     day: Option<i32>,
@@ -738,20 +1059,240 @@ pub struct ParsingResult {
     tzname: Option<String>,
     tzoffset: Option<i32>,
     ampm: Option<bool>,
+    ampm_prefix: Option<bool>,
     century_specified: bool,
     any_unused_tokens: Vec<String>,
+    holiday: Option<HolidayRule>,
+    business_days: Option<i64>,
+    nth_weekday: Option<i32>,
+    named_time: Option<usize>,
+    day_offset: Option<i64>,
+    calendar: Option<Calendar>,
+    // Japanese era the year was given in, as numbered by `era::era_names`
+    era: Option<usize>,
+    spans: FieldSpans,
+    warnings: Vec<Warning>,
+}
+
+/// Where in the input each part of a `ParsingResult` was read from, so that
+/// errors found while building the date can point back at it.
+#[derive(Default, Debug, PartialEq)]
+struct FieldSpans {
+    year: Option<Range<usize>>,
+    month: Option<Range<usize>>,
+    day: Option<Range<usize>>,
+    hour: Option<Range<usize>>,
+    minute: Option<Range<usize>>,
+    second: Option<Range<usize>>,
+    tz: Option<Range<usize>>,
+    weekday: Option<Range<usize>>,
+    // Holidays, weekdays and relative or business day offsets
+    date: Option<Range<usize>>,
+}
+
+// The fields of a `ParsingResult` that aren't read through `YMD`, to tell
+// which of them a run of tokens set
+#[derive(PartialEq)]
+struct FieldValues {
+    hour: Option<i32>,
+    minute: Option<i32>,
+    second: Option<i32>,
+    tz: (Option<String>, Option<i32>),
+    date: (bool, Option<i64>, Option<i32>, Option<usize>, Option<i64>),
+}
+
+impl ParsingResult {
+    fn values(&self) -> FieldValues {
+        FieldValues {
+            hour: self.hour,
+            minute: self.minute,
+            second: self.second,
+            tz: (self.tzname.clone(), self.tzoffset),
+            date: (
+                self.holiday.is_some(),
+                self.business_days,
+                self.nth_weekday,
+                self.weekday,
+                self.day_offset,
+            ),
+        }
+    }
+
+    /// The fields of a canonical ISO 8601 timestamp, with the spans the
+    /// tokenizing parser would have given them.
+    fn from_iso(iso: IsoTimestamp) -> Self {
+        let mut res = ParsingResult {
+            year: Some(iso.year),
+            month: Some(iso.month),
+            day: Some(iso.day),
+            century_specified: true,
+            ..Default::default()
+        };
+        res.spans.year = Some(0..4);
+        res.spans.month = Some(5..7);
+        res.spans.day = Some(8..10);
+
+        if let Some(time) = iso.time {
+            res.hour = Some(time.hour);
+            res.minute = Some(time.minute);
+            res.second = time.second.map(|(second, _)| second);
+            res.microsecond = time.second.map(|(_, micros)| micros);
+            res.spans.hour = Some(time.span.clone());
+            res.spans.minute = Some(time.span.clone());
+            res.spans.second = time.second.map(|_| time.span.clone());
+
+            if let Some((offset, span)) = time.offset {
+                res.tzoffset = Some(offset);
+                res.spans.tz = Some(span);
+            }
+        }
+
+        res
+    }
+
+    /// Attribute the fields that changed since `before` to `span`. Times keep
+    /// the span that first set them, since am/pm only adjusts the hour.
+    fn mark_spans(&mut self, before: &FieldValues, span: &Range<usize>) {
+        if before.hour.is_none() && self.hour.is_some() {
+            self.spans.hour = Some(span.clone());
+        }
+        if before.minute.is_none() && self.minute.is_some() {
+            self.spans.minute = Some(span.clone());
+        }
+        if before.second.is_none() && self.second.is_some() {
+            self.spans.second = Some(span.clone());
+        }
+        if before.tz != (self.tzname.clone(), self.tzoffset) {
+            self.spans.tz = Some(span.clone());
+        }
+        if before.date != self.values().date {
+            self.spans.date = Some(span.clone());
+        }
+        if before.date.3.is_none() && self.weekday.is_some() {
+            self.spans.weekday = Some(span.clone());
+        }
+    }
+
+    /// Note a day given past the end of its month.
+    fn check_day(&mut self, day: u32, max_day: u32) {
+        if let (Some(given), Some(span)) = (self.day, self.spans.day.clone()) {
+            if day > max_day && given as u32 == day {
+                self.warnings.push(Warning::DayClamped { from: day, to: max_day, span });
+            }
+        }
+    }
+
+    /// Note a weekday that `date`, read from the input, doesn't fall on.
+    fn check_weekday(&mut self, date: NaiveDate) {
+        if let (Some(weekday), Some(span)) = (self.weekday, self.spans.weekday.clone()) {
+            if date.weekday().num_days_from_monday() != weekday as u32 {
+                self.warnings.push(Warning::WeekdayIgnored { span });
+            }
+        }
+    }
+
+    /// Warnings for the components the input left out. Dates relative to the
+    /// default only report missing times.
+    fn defaulted(&self) -> Vec<Warning> {
+        let relative = self.day_offset.is_some() || self.business_days.is_some();
+        let named_day = relative || self.holiday.is_some() || self.weekday.is_some();
+
+        vec![
+            (Component::Year, self.year.is_some() || relative),
+            (Component::Month, self.month.is_some() || named_day),
+            (Component::Day, self.day.is_some() || named_day),
+            (Component::Hour, self.hour.is_some()),
+            (Component::Minute, self.minute.is_some()),
+            (Component::Second, self.second.is_some()),
+        ].into_iter()
+            .filter(|&(_, given)| !given)
+            .map(|(component, _)| Warning::Defaulted(component))
+            .collect()
+    }
+
+    /// Error of `kind` pointing at the field it concerns, or at the whole
+    /// input when that field came from the default.
+    fn error(&self, kind: ErrorKind, input: &str) -> ParseError {
+        let span = match kind {
+            ErrorKind::InvalidYear => self.spans.year.as_ref(),
+            ErrorKind::InvalidMonth => self.spans.month.as_ref(),
+            ErrorKind::InvalidDay => self.spans.day.as_ref().or(self.spans.date.as_ref()),
+            ErrorKind::InvalidHour => self.spans.hour.as_ref(),
+            ErrorKind::InvalidMinute => self.spans.minute.as_ref(),
+            ErrorKind::InvalidSecond => self.spans.second.as_ref().or(self.spans.minute.as_ref()),
+            ErrorKind::TimezoneUnsupported => self.spans.tz.as_ref(),
+            _ => None,
+        };
+
+        error_at(kind, input, span.cloned().unwrap_or(0..input.len()))
+    }
+}
+
+/// A parsed timestamp along with anything the parser had to guess.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseOutcome {
+    pub datetime: NaiveDateTime,
+    pub offset: Option<FixedOffset>,
+    /// The tokens that were skipped, when parsing with `fuzzy_with_tokens`.
+    pub skipped_tokens: Option<Vec<String>>,
+    pub warnings: Vec<Warning>,
 }
 
-#[derive(Default)]
 pub struct Parser {
-    info: ParserInfo,
+    info: Arc<ParserInfo>,
+    holidays: Vec<Box<dyn HolidayCalendar>>,
+    business: Option<BusinessCalendar>,
+}
+
+/// The default `ParserInfo`, built once and shared, unless the year has
+/// changed since: its reference year comes from the system clock.
+fn default_info() -> Arc<ParserInfo> {
+    let year = SystemClock.now().year();
+    if let Ok(info) = DEFAULT_INFO.read() {
+        if info.year == year {
+            return info.clone();
+        }
+    }
+
+    let info = Arc::new(ParserInfo::default());
+    if let Ok(mut shared) = DEFAULT_INFO.write() {
+        *shared = info.clone();
+    }
+    info
+}
+
+impl Default for Parser {
+    fn default() -> Self {
+        Parser {
+            info: default_info(),
+            holidays: Vec::new(),
+            business: None,
+        }
+    }
 }
 
 impl Parser {
     pub fn new(info: ParserInfo) -> Self {
-        Parser { info }
+        Parser {
+            info: Arc::new(info),
+            holidays: Vec::new(),
+            business: None,
+        }
     }
 
+    /// Recognize holiday names ("Christmas 2003", "Thanksgiving") using the
+    /// given calendar. Calendars are consulted in the order they were added.
+    pub fn add_holiday_calendar<C: HolidayCalendar + 'static>(&mut self, calendar: C) {
+        self.holidays.push(Box::new(calendar));
+    }
+
+    /// Recognize business-day expressions ("T+3", "3 business days after
+    /// 2003-09-25", "next business day") and resolve them with `calendar`.
+    pub fn set_business_calendar(&mut self, calendar: BusinessCalendar) {
+        self.business = Some(calendar);
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn parse(
         &mut self,
         timestr: &str,
@@ -763,22 +1304,102 @@ impl Parser {
         ignoretz: bool,
         tzinfos: HashMap<String, i32>,
     ) -> ParseResult<(NaiveDateTime, Option<FixedOffset>, Option<Vec<String>>)> {
-        let default_date = default.unwrap_or(&Local::now().naive_local()).date();
+        let outcome = self.parse_outcome(
+            timestr,
+            dayfirst,
+            yearfirst,
+            fuzzy,
+            fuzzy_with_tokens,
+            default,
+            ignoretz,
+            tzinfos,
+        )?;
+
+        Ok((outcome.datetime, outcome.offset, outcome.skipped_tokens))
+    }
 
-        let default_ts = NaiveDateTime::new(default_date, NaiveTime::from_hms(0, 0, 0));
+    /// Like `parse`, but also returns the warnings collected along the way.
+    /// With the `log` feature each warning is logged as well.
+    ///
+    /// ```
+    /// use dtparse::Component;
+    /// use dtparse::Parser;
+    /// use dtparse::Warning;
+    /// use dtparse::YearPivot;
+    /// use std::collections::HashMap;
+    ///
+    /// let outcome = Parser::default()
+    ///     .parse_outcome("Sep 25 03 10:36 XYZ", None, None, false, false, None, false, HashMap::new())
+    ///     .unwrap();
+    /// assert_eq!(outcome.offset, None);
+    /// assert_eq!(
+    ///     outcome.warnings,
+    ///     vec![
+    ///         Warning::YearPivoted { from: 3, to: 2003, span: 7..9, pivot: YearPivot::SlidingWindow },
+    ///         Warning::UnknownTimezone { name: "XYZ".to_owned(), span: 16..19 },
+    ///         Warning::Defaulted(Component::Second),
+    ///     ]
+    /// );
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn parse_outcome(
+        &mut self,
+        timestr: &str,
+        dayfirst: Option<bool>,
+        yearfirst: Option<bool>,
+        fuzzy: bool,
+        fuzzy_with_tokens: bool,
+        default: Option<&NaiveDateTime>,
+        ignoretz: bool,
+        tzinfos: HashMap<String, i32>,
+    ) -> ParseResult<ParseOutcome> {
+        let default_date = default.cloned().unwrap_or_else(|| self.info.clock.now()).date();
 
-        // TODO: What should be done with the tokens?
-        let (res, tokens) =
+        let default_ts = default_date.and_time(NaiveTime::default());
+
+        let (mut res, tokens) =
             self.parse_with_tokens(timestr, dayfirst, yearfirst, fuzzy, fuzzy_with_tokens)?;
 
-        let naive = self.build_naive(&res, &default_ts)?;
+        let defaulted = res.defaulted();
+        let naive = self.build_naive(&mut res, &default_ts).map_err(|kind| res.error(kind, timestr))?;
+        let naive = match (res.business_days, self.business.as_ref()) {
+            (Some(days), Some(calendar)) => calendar
+                .add_business_days(naive.date(), days)
+                .map(|date| NaiveDateTime::new(date, naive.time()))
+                .ok_or_else(|| res.error(ErrorKind::InvalidDay, timestr))?,
+            _ => naive,
+        };
 
-        if !ignoretz {
-            let offset = self.build_tzaware(&naive, &res, tzinfos)?;
-            Ok((naive, offset, tokens))
+        let offset = if !ignoretz {
+            self.build_tzaware(&naive, &mut res, tzinfos)
+                .map_err(|kind| res.error(kind, timestr))?
         } else {
-            Ok((naive, None, tokens))
+            None
+        };
+
+        // Warnings about the input in the order it reads, then the
+        // components it left out
+        let mut warnings = res.warnings;
+        warnings.sort_by_key(|warning| match *warning {
+            Warning::UnknownTimezone { ref span, .. }
+            | Warning::YearPivoted { ref span, .. }
+            | Warning::WeekdayIgnored { ref span }
+            | Warning::DayClamped { ref span, .. } => span.start,
+            Warning::Defaulted(_) => 0,
+        });
+        warnings.extend(defaulted);
+
+        #[cfg(feature = "log")]
+        for warning in &warnings {
+            log_warning(warning);
         }
+
+        Ok(ParseOutcome {
+            datetime: naive,
+            offset,
+            skipped_tokens: tokens,
+            warnings,
+        })
     }
 
     fn parse_with_tokens(
@@ -802,9 +1423,40 @@ impl Parser {
             self.info.yearfirst
         };
 
+        // Canonical ISO 8601 leaves nothing for `parse_heuristic` to decide,
+        // unless the options or vocabulary would have it read differently:
+        // dayfirst swaps a month and day of 12 or less, as in dateutil
+        let iso = if fuzzy || dayfirst || self.info.calendar != Calendar::Gregorian {
+            None
+        } else {
+            parse_iso(timestr).filter(|iso| {
+                iso.time.as_ref().map_or(true, |time| self.info.get_jump(time.separator))
+            })
+        };
+        if let Some(iso) = iso {
+            let mut res = ParsingResult::from_iso(iso);
+            self.info
+                .validate(&mut res)
+                .map_err(|kind| res.error(kind, timestr))?;
+            return Ok((res, None));
+        }
+
+        self.parse_heuristic(timestr, dayfirst, yearfirst, fuzzy, fuzzy_with_tokens)
+    }
+
+    fn parse_heuristic(
+        &mut self,
+        timestr: &str,
+        dayfirst: bool,
+        yearfirst: bool,
+        fuzzy: bool,
+        fuzzy_with_tokens: bool,
+    ) -> Result<(ParsingResult, Option<Vec<String>>), ParseError> {
         let mut res = ParsingResult::default();
 
-        let mut l = tokenize(&timestr);
+        let tokens = segment(replace_number_words(tokenize_spans(timestr)), |t| self.info.knows(t));
+        let spans: Vec<Range<usize>> = tokens.iter().map(|t| t.span.clone()).collect();
+        let mut l: Vec<&str> = tokens.iter().map(|t| &*t.text).collect();
         let mut skipped_idxs: Vec<usize> = Vec::new();
 
         let mut ymd = YMD::default();
@@ -812,140 +1464,271 @@ impl Parser {
         let len_l = l.len();
         let mut i = 0;
 
+        // Input covered by tokens `first` through `last`
+        let span_of = |first: usize, last: usize| match (spans.get(first), spans.get(last.min(len_l.saturating_sub(1)))) {
+            (Some(first), Some(last)) => first.start..last.end,
+            _ => timestr.len()..timestr.len(),
+        };
+        let fail = |kind: ErrorKind, first: usize, last: usize| error_at(kind, timestr, span_of(first, last));
+
         while i < len_l {
-            let value_repr = l[i].clone();
+            let value_repr = l[i];
+            let word = self.info.word(l[i]);
+            let start = i;
+            let before = res.values();
 
-            if let Ok(v) = Decimal::from_str(&value_repr) {
-                i = self.parse_numeric_token(&l, i, &self.info, &mut ymd, &mut res, fuzzy)?;
-            } else if let Some(value) = self.info.get_weekday(&l[i]) {
+            let business_offset = if self.business.is_some() {
+                find_business_offset(&l, i)
+            } else {
+                None
+            };
+
+            if let Some((days, last_idx)) = business_offset {
+                let total = res.business_days.unwrap_or(0).checked_add(days);
+                res.business_days = Some(total.ok_or_else(|| fail(ErrorKind::InvalidDay, i, last_idx))?);
+                i = last_idx;
+            } else if let Some((n, weekday, last_idx)) = self.find_nth_weekday(&l, i) {
+                // third Tuesday [in November], last Monday [of May]
+                res.nth_weekday = Some(n);
+                res.weekday = Some(weekday);
+                i = last_idx;
+            } else if let Some((hour, minute, last_idx)) = find_clock_phrase(&l, i) {
+                // half past three, quarter to five, ten o'clock
+                res.hour = Some(hour);
+                res.minute = Some(minute);
+                i = last_idx;
+            } else if let Some((calendar, last_idx)) = find_calendar_era(&self.info.eras, &l, i) {
+                // 2546 BE, พ.ศ. 2546, 民國92年
+                res.calendar = Some(calendar);
+                i = last_idx;
+            } else if let Some((year, last_idx)) = word.era.and_then(|era| find_era_year(era, &l, i)) {
+                // 平成15年, 令和元年
+                ymd.append(year, &year.to_string(), span_of(i, last_idx), Some(YMDLabel::Year))
+                    .map_err(|kind| fail(kind, i, last_idx))?;
+                res.calendar = Some(Calendar::Gregorian);
+                res.era = word.era;
+                i = last_idx;
+            } else if Numeric::parse(value_repr).is_some() {
+                i = self.parse_numeric_token(&l, &spans, i, &self.info, &mut ymd, &mut res, fuzzy)
+                    .map_err(|kind| fail(kind, i, i))?;
+            } else if let Some((rule, last_idx)) = self.find_holiday(&l, i) {
+                res.holiday = Some(rule);
+                i = last_idx;
+            } else if let Some(value) = word.weekday {
                 res.weekday = Some(value);
-            } else if let Some(value) = self.info.get_month(&l[i]) {
-                ymd.append(value as i32, &l[i], Some(YMDLabel::Month));
+            } else if let Some((month, last_idx)) = find_calendar_month(self.info.calendar, &l, i) {
+                // Shahrivar, Rabi' al-Awwal, Adar II
+                ymd.append(month as i32, l[i], span_of(i, last_idx), Some(YMDLabel::Month))
+                    .map_err(|kind| fail(kind, i, last_idx))?;
+                i = last_idx;
+            } else if let Some(value) = word.month {
+                ymd.append(value as i32 + 1, l[i], spans[i].clone(), Some(YMDLabel::Month));
 
                 if i + 1 < len_l {
                     if l[i + 1] == "-" || l[i + 1] == "/" {
                         // Jan-01[-99]
                         let sep = &l[i + 1];
-                        // TODO: This seems like a very unsafe unwrap
-                        ymd.append(l[i + 2].parse::<i32>().unwrap(), &l[i + 2], None);
+                        let value = integer_at(&l, i + 2).map_err(|kind| fail(kind, i + 2, i + 2))?;
+                        ymd.append(value, l[i + 2], spans[i + 2].clone(), None);
 
                         if i + 3 < len_l && &l[i + 3] == sep {
                             // Jan-01-99
-                            ymd.append(l[i + 4].parse::<i32>().unwrap(), &l[i + 4], None);
+                            let value = integer_at(&l, i + 4).map_err(|kind| fail(kind, i + 4, i + 4))?;
+                            ymd.append(value, l[i + 4], spans[i + 4].clone(), None);
                             i += 2;
                         }
 
                         i += 2;
                     } else if (i + 4 < len_l && l[i + 1] == l[i + 3] && l[i + 3] == " "
-                        && self.info.get_pertain(&l[i + 2]))
+                        && self.info.get_pertain(l[i + 2]))
                     {
                         // Jan of 01
-                        if let Some(value) = l[i + 4].parse::<i32>().ok() {
-                            let year = self.info.convertyear(value, false);
-                            ymd.append(year, &l[i + 4], Some(YMDLabel::Year));
+                        if let Ok(value) = l[i + 4].parse::<i32>() {
+                            ymd.append(value, l[i + 4], spans[i + 4].clone(), Some(YMDLabel::Year));
                         }
 
                         i += 4;
                     }
                 }
-            } else if let Some(value) = self.info.get_ampm(&l[i]) {
+            } else if let Some(offset) = word.relative_day {
+                // today, tomorrow, tonight
+                res.day_offset = Some(offset);
+                if let Some(hour) = word.named_time {
+                    res.named_time = Some(hour);
+                }
+            } else if let Some(hour) = word.named_time {
+                // noon, midnight, evening
+                res.named_time = Some(hour);
+            } else if let Some(value) = word.ampm.map(|v| v == 1) {
                 let is_ampm = self.ampm_valid(res.hour, res.ampm, fuzzy);
-
-                if is_ampm.is_ok() {
-                    res.hour = Some(self.adjust_ampm(res.hour.unwrap(), value));
+                let number_follows = l[i + 1..]
+                    .iter()
+                    .find(|&&t| t != " ")
+                    .map_or(false, |t| t.chars().all(|c| c.is_ascii_digit()));
+
+                if self.info.ampm_prefix && res.hour.is_none() && number_follows {
+                    // 午後3時: applied once the hour is known
+                    res.ampm_prefix = Some(value);
+                } else if let (Ok(true), Some(hour)) = (is_ampm, res.hour) {
+                    res.hour = Some(self.adjust_ampm(hour, value));
                     res.ampm = Some(value);
                 } else if fuzzy {
                     skipped_idxs.push(i);
                 }
-            } else if self.could_be_tzname(res.hour, res.tzname.clone(), res.tzoffset, &l[i]) {
-                res.tzname = Some(l[i].clone());
-
-                let tzname = res.tzname.clone().unwrap();
-                res.tzoffset = self.info.get_tzoffset(&tzname).map(|t| t as i32);
+            } else if self.could_be_tzname(res.hour, res.tzname.clone(), res.tzoffset, l[i]) {
+                let tzname = l[i];
+                res.tzname = Some(tzname.to_owned());
+                res.tzoffset = self.info.get_tzoffset(tzname);
 
                 if i + 1 < len_l && (l[i + 1] == "+" || l[i + 1] == "-") {
                     // GMT+3
                     // According to dateutil docs - reverse the size, as GMT+3 means
                     // "my time +3 is GMT" not "GMT +3 is my time"
 
-                    // TODO: Is there a better way of in-place modifying a vector?
-                    let item = if l[i + 1] == "+" {
-                        "-".to_owned()
-                    } else {
-                        "-".to_owned()
-                    };
-                    l.remove(i + 1);
-                    l.insert(i + 1, item);
+                    // TODO: dateutil also turns "-" into "+"
+                    l[i + 1] = "-";
 
                     res.tzoffset = None;
 
-                    if self.info.get_utczone(&tzname) {
+                    if self.info.get_utczone(tzname) {
                         res.tzname = None;
                     }
                 }
             } else if res.hour.is_some() && (l[i] == "+" || l[i] == "-") {
                 let signal = if l[i] == "+" { 1 } else { -1 };
-                let len_li = l[i].len();
-
-                let mut hour_offset: Option<i32> = None;
-                let mut min_offset: Option<i32> = None;
+                let offset = l.get(i + 1).map_or("", |&s| s);
+                let len_li = offset.len();
 
-                // TODO: check that l[i + 1] is integer?
-                if len_li == 4 {
+                let hours = parse_offset(offset).map_err(|kind| fail(kind, i + 1, i + 1));
+                let (hour_offset, min_offset) = if len_li == 4 {
                     // -0300
-                    hour_offset = Some(l[i + 1][..2].parse::<i32>().unwrap());
-                    min_offset = Some(l[i + 1][2..4].parse::<i32>().unwrap());
-                } else if i + 2 < len_l && l[i + 2] == ":" {
+                    let value = hours?;
+                    (value / 100, value % 100)
+                } else if i + 3 < len_l && l[i + 2] == ":" {
                     // -03:00
-                    hour_offset = Some(l[i + 1].parse::<i32>().unwrap());
-                    min_offset = Some(l[i + 3].parse::<i32>().unwrap());
+                    let minutes = parse_offset(l[i + 3]).map_err(|kind| fail(kind, i + 3, i + 3))?;
                     i += 2;
+                    (hours?, minutes)
                 } else if len_li <= 2 {
                     // -[0]3
-                    hour_offset = Some(l[i + 1][..2].parse::<i32>().unwrap());
-                    min_offset = Some(0);
-                }
+                    (hours?, 0)
+                } else {
+                    return Err(fail(ErrorKind::UnrecognizedToken, i + 1, i + 1));
+                };
 
-                res.tzoffset =
-                    Some(signal * (hour_offset.unwrap() * 3600 + min_offset.unwrap() * 60));
+                res.tzoffset = Some(signal * (hour_offset * 3600 + min_offset * 60));
 
                 let tzname = res.tzname.clone();
-                if i + 5 < len_l && self.info.get_jump(&l[i + 2]) && l[i + 3] == "("
+                if i + 5 < len_l && self.info.get_jump(l[i + 2]) && l[i + 3] == "("
                     && l[i + 5] == ")" && 3 <= l[i + 4].len()
-                    && self.could_be_tzname(res.hour, tzname, None, &l[i + 4])
+                    && self.could_be_tzname(res.hour, tzname, None, l[i + 4])
                 {
                     // (GMT)
-                    res.tzname = Some(l[i + 4].clone());
+                    res.tzname = Some(l[i + 4].to_owned());
                     i += 4;
                 }
 
                 i += 1;
-            } else if !self.info.get_jump(&l[i]) || fuzzy {
-                return Err(ParseError::UnrecognizedToken(l[i].clone()));
+            } else if !word.jump || fuzzy {
+                return Err(fail(ErrorKind::UnrecognizedToken, i, i));
             } else {
                 skipped_idxs.push(i);
             }
 
+            res.mark_spans(&before, &span_of(start, i));
             i += 1;
         }
 
-        let (year, month, day) = ymd.resolve_ymd(yearfirst, dayfirst)?;
+        if let (Some(value), Some(hour)) = (res.ampm_prefix, res.hour) {
+            if res.ampm.is_none() && (0..=12).contains(&hour) {
+                res.hour = Some(self.adjust_ampm(hour, value));
+                res.ampm = Some(value);
+            }
+        }
+
+        if let Some(named_hour) = res.named_time {
+            match res.hour {
+                None => res.hour = Some(named_hour as i32),
+                Some(hour) if res.ampm.is_none() => {
+                    res.hour = Some(self.adjust_ampm(hour, named_hour >= 12))
+                }
+                _ => (),
+            }
+        }
+
+        let (year, month, day) = ymd.resolve_ymd(yearfirst, dayfirst).map_err(|kind| {
+            match (ymd.spans.first(), ymd.spans.last()) {
+                (Some(first), Some(last)) => error_at(kind, timestr, first.start..last.end),
+                _ => error_at(kind, timestr, 0..timestr.len()),
+            }
+        })?;
 
         res.century_specified = ymd.century_specified;
-        res.year = year;
-        res.month = month;
-        res.day = day;
-
-        if !self.info.validate(&mut res) {
-            Err(ParseError::InvalidParseResult(res))
-        } else if fuzzy_with_tokens {
-            let skipped_tokens = skipped_idxs.into_iter().map(|i| l[i].clone()).collect();
+        res.year = year.and_then(|i| ymd._ymd.get(i).cloned());
+        res.month = month.and_then(|i| ymd._ymd.get(i).cloned());
+        res.day = day.and_then(|i| ymd._ymd.get(i).cloned());
+        res.spans.year = year.and_then(|i| ymd.spans.get(i).cloned());
+        res.spans.month = month.and_then(|i| ymd.spans.get(i).cloned());
+        res.spans.day = day.and_then(|i| ymd.spans.get(i).cloned());
+
+        self.info
+            .validate(&mut res)
+            .map_err(|kind| res.error(kind, timestr))?;
+        if fuzzy_with_tokens {
+            let skipped_tokens = skipped_idxs.into_iter().map(|i| l[i].to_owned()).collect();
             Ok((res, Some(skipped_tokens)))
         } else {
             Ok((res, None))
         }
     }
 
+    /// Find the longest holiday name starting at `idx`, returning its rule and
+    /// the index of the last token that is part of the name.
+    fn find_holiday(&self, tokens: &[&str], idx: usize) -> Option<(HolidayRule, usize)> {
+        if self.holidays.is_empty() || !tokens[idx].chars().all(|c| c.is_alphabetic()) {
+            return None;
+        }
+
+        let mut name = String::new();
+        let mut found = None;
+
+        for (i, &token) in tokens.iter().enumerate().skip(idx).take(MAX_HOLIDAY_TOKENS) {
+            if token.chars().all(|c| c.is_alphabetic()) {
+                if !name.is_empty() && tokens[i - 1] != "'" {
+                    name.push(' ');
+                }
+                name.push_str(&token.to_lowercase());
+
+                if let Some(rule) = self.holidays.iter().filter_map(|c| c.holiday(&name)).next() {
+                    found = Some((rule, i));
+                }
+            } else if token != " " && token != "'" && token != "." && token != "-" {
+                break;
+            }
+        }
+
+        found
+    }
+
+    /// Recognize "3rd Tuesday" or "last Monday", returning the (possibly
+    /// negative) occurrence, the weekday and the index of the weekday token.
+    fn find_nth_weekday(&self, tokens: &[&str], idx: usize) -> Option<(i32, usize, usize)> {
+        let (n, suffix_idx) = if tokens[idx].eq_ignore_ascii_case("last") {
+            (-1, idx)
+        } else if idx + 1 < tokens.len() && is_ordinal_suffix(tokens[idx + 1]) {
+            (tokens[idx].parse::<i32>().ok()?, idx + 1)
+        } else {
+            return None;
+        };
+
+        if suffix_idx + 2 < tokens.len() && tokens[suffix_idx + 1] == " " {
+            let weekday = self.info.get_weekday(tokens[suffix_idx + 2])?;
+            Some((n, weekday, suffix_idx + 2))
+        } else {
+            None
+        }
+    }
+
     fn could_be_tzname(
         &self,
         hour: Option<i32>,
@@ -956,11 +1739,11 @@ impl Parser {
         let all_ascii_upper = token
             .chars()
             .all(|c| 65u8 as char <= c && c <= 90u8 as char);
-        return hour.is_some() && tzname.is_none() && tzoffset.is_none() && token.len() <= 5
-            && all_ascii_upper;
+        hour.is_some() && tzname.is_none() && tzoffset.is_none() && token.len() <= 5
+            && all_ascii_upper
     }
 
-    fn ampm_valid(&self, hour: Option<i32>, ampm: Option<bool>, fuzzy: bool) -> ParseResult<bool> {
+    fn ampm_valid(&self, hour: Option<i32>, ampm: Option<bool>, fuzzy: bool) -> ParseIResult<bool> {
         if fuzzy && ampm == Some(true) {
             return Ok(false);
         }
@@ -969,119 +1752,202 @@ impl Parser {
             if fuzzy {
                 Ok(false)
             } else {
-                Err(ParseError::AmPmWithoutHour)
+                Err(ErrorKind::AmPmWithoutHour)
             }
-        } else if !(0 <= hour.unwrap() && hour.unwrap() <= 12) {
+        } else if hour.map_or(false, |hour| !(0..=12).contains(&hour)) {
             if fuzzy {
                 Ok(false)
             } else {
-                Err(ParseError::InvalidHour)
+                Err(ErrorKind::InvalidHour)
             }
         } else {
-            Ok(false)
+            Ok(true)
         }
     }
 
-    fn build_naive(&self, res: &ParsingResult, default: &NaiveDateTime) -> ParseResult<NaiveDateTime> {
-        let y = res.year.unwrap_or(default.year());
-        let m = res.month.unwrap_or(default.month() as i32) as u32;
+    fn build_naive(&self, res: &mut ParsingResult, default: &NaiveDateTime) -> ParseIResult<NaiveDateTime> {
+        let calendar = res.calendar.unwrap_or(self.info.calendar);
+        let d = if calendar::converts_dates(calendar) {
+            self.build_converted_date(calendar, res, default)?
+        } else {
+            self.build_date(res, default)?
+        };
 
-        let d_offset = if res.weekday.is_some() && res.day.is_none() {
-            // TODO: Unwrap not justified
-            let dow = day_of_week(y as u32, m, default.day()).unwrap();
+        let d = d.checked_add_signed(Duration::days(res.day_offset.unwrap_or(0)))
+            .ok_or(ErrorKind::InvalidDay)?;
 
-            // UNWRAP: We've already check res.weekday() is some
-            let actual_weekday = (res.weekday.unwrap() + 1) % 7;
-            let other = DayOfWeek::from_numeral(actual_weekday as u32);
-            Duration::days(dow.difference(other) as i64)
+        let hour = res.hour.unwrap_or(default.hour() as i32);
+        let minute = res.minute.unwrap_or(default.minute() as i32);
+        let second = res.second.unwrap_or(default.second() as i32);
+        let microsecond = res.microsecond
+            .unwrap_or(default.nanosecond() as i32 / 1000);
+
+        let t = if !(0..=23).contains(&hour) {
+            return Err(ErrorKind::InvalidHour);
+        } else if !(0..=59).contains(&minute) {
+            return Err(ErrorKind::InvalidMinute);
         } else {
-            Duration::days(0)
+            NaiveTime::from_hms_micro_opt(hour as u32, minute as u32, second as u32, microsecond as u32)
+                .ok_or(ErrorKind::InvalidSecond)?
+        };
+
+        Ok(NaiveDateTime::new(d, t))
+    }
+
+    /// Date from a result whose year, month and day are in a calendar with
+    /// its own months. Missing fields come from `default` in that calendar;
+    /// holidays and nth weekdays are Gregorian and aren't applied.
+    fn build_converted_date(
+        &self,
+        calendar: Calendar,
+        res: &mut ParsingResult,
+        default: &NaiveDateTime,
+    ) -> ParseIResult<NaiveDate> {
+        let (default_y, default_m, default_d) =
+            calendar::from_gregorian(calendar, default.date()).ok_or(ErrorKind::InvalidDay)?;
+
+        let y = res.year.unwrap_or(default_y);
+        let m = res.month.map(|m| m as u32).unwrap_or(default_m);
+        let max_day = calendar::days_in_month(calendar, y, m).ok_or(ErrorKind::InvalidMonth)?;
+        let day = res.day.map(|d| d as u32).unwrap_or(default_d);
+        res.check_day(day, max_day);
+        let d = calendar::to_gregorian(calendar, y, m, min(day, max_day)).ok_or(ErrorKind::InvalidDay)?;
+
+        match (res.weekday, res.day) {
+            (Some(weekday), None) => {
+                let dow = DayOfWeek::from_numeral(d.weekday().num_days_from_sunday());
+                let other = DayOfWeek::from_numeral((weekday as u32 + 1) % 7);
+                d.checked_add_signed(Duration::days(dow.difference(other) as i64))
+                    .ok_or(ErrorKind::InvalidDay)
+            }
+            _ => {
+                res.check_weekday(d);
+                Ok(d)
+            }
+        }
+    }
+
+    fn build_date(&self, res: &mut ParsingResult, default: &NaiveDateTime) -> ParseIResult<NaiveDate> {
+        let y = res.year.unwrap_or(default.year());
+        let mut m = res.month.unwrap_or(default.month() as i32) as u32;
+        let mut day = res.day;
+
+        if let Some(rule) = res.holiday {
+            let date = rule.resolve(y).ok_or(ErrorKind::InvalidDay)?;
+            if res.month.is_none() && res.day.is_none() {
+                m = date.month();
+                day = Some(date.day() as i32);
+            }
+        }
+
+        if let (Some(n), Some(weekday), None) = (res.nth_weekday, res.weekday, day) {
+            let weekday = DayOfWeek::from_numeral((weekday as u32 + 1) % 7);
+            let nth = nth_weekday_of_month(y, m, weekday, n).ok_or(ErrorKind::InvalidDay)?;
+            day = Some(nth as i32);
+        }
+
+        // A weekday picks the day only when the input gives none
+        let weekday_given = day.is_some() && res.nth_weekday.is_none();
+        let d_offset = match (res.weekday, day) {
+            (Some(weekday), None) if y >= 1 => {
+                let dow = day_of_week(y as u32, m, default.day())?;
+                let other = DayOfWeek::from_numeral((weekday as u32 + 1) % 7);
+                Duration::days(dow.difference(other) as i64)
+            }
+            (Some(_), None) => return Err(ErrorKind::InvalidYear),
+            _ => Duration::days(0),
         };
 
         // TODO: Change month/day to u32
-        let mut d = NaiveDate::from_ymd(
-            y,
-            m,
-            min(res.day.unwrap_or(default.day() as i32) as u32, days_in_month(y, m as i32)?)
-        );
-
-        let d = d + d_offset;
-
-        let t = NaiveTime::from_hms_micro(
-            res.hour.unwrap_or(default.hour() as i32) as u32,
-            res.minute.unwrap_or(default.minute() as i32) as u32,
-            res.second.unwrap_or(default.second() as i32) as u32,
-            res.microsecond
-                .unwrap_or(default.timestamp_subsec_micros() as i32) as u32,
-        );
+        let day = match day {
+            Some(day) if day < 1 => return Err(ErrorKind::InvalidDay),
+            Some(day) => day as u32,
+            None => default.day(),
+        };
+        let max_day = days_in_month(y, m as i32)?;
+        res.check_day(day, max_day);
+        let d = NaiveDate::from_ymd_opt(y, m, min(day, max_day))
+            .ok_or(ErrorKind::InvalidYear)?;
 
-        Ok(NaiveDateTime::new(d, t))
+        if weekday_given {
+            res.check_weekday(d);
+        }
+        d.checked_add_signed(d_offset).ok_or(ErrorKind::InvalidYear)
     }
 
     fn build_tzaware(
         &self,
         dt: &NaiveDateTime,
-        res: &ParsingResult,
+        res: &mut ParsingResult,
         tzinfos: HashMap<String, i32>,
-    ) -> ParseResult<Option<FixedOffset>> {
+    ) -> ParseIResult<Option<FixedOffset>> {
         // TODO: Actual timezone support
         if let Some(offset) = res.tzoffset {
-            Ok(Some(FixedOffset::east(offset)))
-        } else if res.tzoffset == None
+            FixedOffset::east_opt(offset)
+                .map(Some)
+                .ok_or(ErrorKind::TimezoneUnsupported)
+        } else if res.tzoffset.is_none()
             && (res.tzname == Some(" ".to_owned()) || res.tzname == Some(".".to_owned())
-                || res.tzname == Some("-".to_owned()) || res.tzname == None)
+                || res.tzname == Some("-".to_owned()) || res.tzname.is_none())
         {
             Ok(None)
-        } else if res.tzname.is_some() && tzinfos.contains_key(res.tzname.as_ref().unwrap()) {
-            Ok(Some(FixedOffset::east(
-                tzinfos.get(res.tzname.as_ref().unwrap()).unwrap().clone(),
-            )))
-        } else if res.tzname.is_some() {
-            // TODO: Dateutil issues a warning/deprecation notice here. Should we force the issue?
-            println!("tzname {} identified but not understood. Ignoring for the time being, but behavior is subject to change.", res.tzname.as_ref().unwrap());
+        } else if let Some(&offset) = res.tzname.as_ref().and_then(|name| tzinfos.get(name)) {
+            FixedOffset::east_opt(offset)
+                .map(Some)
+                .ok_or(ErrorKind::TimezoneUnsupported)
+        } else if let Some(name) = res.tzname.clone() {
+            // dateutil warns here too and returns a naive result
+            let span = res.spans.tz.clone().unwrap_or(0..0);
+            res.warnings.push(Warning::UnknownTimezone { name, span });
             Ok(None)
         } else {
-            Err(ParseError::TimezoneUnsupported)
+            Err(ErrorKind::TimezoneUnsupported)
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn parse_numeric_token(
         &self,
-        tokens: &Vec<String>,
+        tokens: &[&str],
+        spans: &[Range<usize>],
         idx: usize,
         info: &ParserInfo,
         ymd: &mut YMD,
         res: &mut ParsingResult,
         fuzzy: bool,
-    ) -> Result<usize, ParseInternalError> {
+    ) -> ParseIResult<usize> {
         let mut idx = idx;
         let value_repr = &tokens[idx];
-        let mut value = Decimal::from_str(&value_repr).unwrap();
+        let mut value = to_numeric(value_repr)?;
 
         let len_li = value_repr.len();
         let len_l = tokens.len();
 
         // TODO: I miss the `x in y` syntax
         // TODO: Decompose this logic a bit
-        if ymd.len() == 3 && (len_li == 2 || len_li == 4) && res.hour.is_none()
+        if let Some(label) = tokens.get(idx + 1).and_then(|t| info.get_ymd(t)) {
+            // 2003年, 9月, 25日
+            ymd.append(parse_int(value_repr)?, value_repr, spans[idx].clone(), Some(label))?;
+            idx += 1;
+        } else if ymd.len() == 3 && (len_li == 2 || len_li == 4) && res.hour.is_none()
             && (idx + 1 >= len_l
-                || (tokens[idx + 1] != ":" && info.get_hms(&tokens[idx + 1]).is_none()))
+                || (tokens[idx + 1] != ":" && info.get_hms(tokens[idx + 1]).is_none()))
         {
             // 1990101T32[59]
             let s = &tokens[idx];
             res.hour = s[0..2].parse::<i32>().ok();
 
             if len_li == 4 {
-                res.minute = Some(s[2..4].parse::<i32>()?)
+                res.minute = Some(parse_int(&s[2..4])?)
             }
         } else if len_li == 6 || (len_li > 6 && tokens[idx].find(".") == Some(6)) {
             // YYMMDD or HHMMSS[.ss]
             let s = &tokens[idx];
 
-            if ymd.len() == 0 && tokens[idx].find(".") == None {
-                ymd.append(s[0..2].parse::<i32>().unwrap(), &s[0..2], None);
-                ymd.append(s[2..4].parse::<i32>().unwrap(), &s[2..4], None);
-                ymd.append(s[4..6].parse::<i32>().unwrap(), &s[4..6], None);
+            if ymd.len() == 0 && tokens[idx].find(".").is_none() {
+                ymd.append(parse_int(&s[0..2])?, &s[0..2], sub_span(&spans[idx], s, 0..2), None);
+                ymd.append(parse_int(&s[2..4])?, &s[2..4], sub_span(&spans[idx], s, 2..4), None);
+                ymd.append(parse_int(&s[4..6])?, &s[4..6], sub_span(&spans[idx], s, 4..6), None);
             } else {
                 // 19990101T235959[.59]
                 res.hour = s[0..2].parse::<i32>().ok();
@@ -1091,42 +1957,40 @@ impl Parser {
                 res.second = Some(t.0);
                 res.microsecond = Some(t.1);
             }
-        } else if vec![8, 12, 14].contains(&len_li) {
+        } else if [8, 12, 14].contains(&len_li) {
             // YYMMDD
             let s = &tokens[idx];
-            ymd.append(s[..4].parse::<i32>().unwrap(), &s[..4], Some(YMDLabel::Year));
-            ymd.append(s[4..6].parse::<i32>().unwrap(), &s[4..6], None);
-            ymd.append(s[6..8].parse::<i32>().unwrap(), &s[6..8], None);
+            ymd.append(parse_int(&s[..4])?, &s[..4], sub_span(&spans[idx], s, 0..4), Some(YMDLabel::Year));
+            ymd.append(parse_int(&s[4..6])?, &s[4..6], sub_span(&spans[idx], s, 4..6), None);
+            ymd.append(parse_int(&s[6..8])?, &s[6..8], sub_span(&spans[idx], s, 6..8), None);
 
             if len_li > 8 {
-                res.hour = Some(s[8..10].parse::<i32>()?);
-                res.minute = Some(s[10..12].parse::<i32>()?);
+                res.hour = Some(parse_int(&s[8..10])?);
+                res.minute = Some(parse_int(&s[10..12])?);
 
                 if len_li > 12 {
-                    res.second = Some(s[12..].parse::<i32>()?);
+                    res.second = Some(parse_int(&s[12..])?);
                 }
             }
         } else if let Some(hms_idx) = self.find_hms_index(idx, tokens, info, true) {
             // HH[ ]h or MM[ ]m or SS[.ss][ ]s
             let (new_idx, hms) = self.parse_hms(idx, tokens, info, Some(hms_idx));
-            if hms.is_some() {
-                // TODO: This unwrap is unjustified.
-                self.assign_hms(res, value_repr, hms.unwrap());
+            if let Some(hms) = hms {
+                self.assign_hms(res, value_repr, hms)?;
             }
             idx = new_idx;
         } else if idx + 2 < len_l && tokens[idx + 1] == ":" {
             // HH:MM[:SS[.ss]]
-            // TODO: Better story around Decimal handling
-            res.hour = Some(value.floor().to_i64().unwrap() as i32);
+            res.hour = Some(value.to_i32()?);
             // TODO: Rescope `value` here?
-            value = self.to_decimal(&tokens[idx + 2]);
-            let min_sec = self.parse_min_sec(value);
+            value = to_numeric(tokens[idx + 2])?;
+            let min_sec = self.parse_min_sec(value)?;
             res.minute = Some(min_sec.0);
             res.second = min_sec.1;
 
             if idx + 4 < len_l && tokens[idx + 3] == ":" {
                 // TODO: (x, y) = (a, b) syntax?
-                let ms = self.parsems(&tokens[idx + 4]).unwrap();
+                let ms = self.parsems(tokens[idx + 4])?;
                 res.second = Some(ms.0);
                 res.microsecond = Some(ms.1);
 
@@ -1138,20 +2002,21 @@ impl Parser {
         {
             // TODO: There's got to be a better way of handling the condition above
             let sep = &tokens[idx + 1];
-            ymd.append(value_repr.parse::<i32>().unwrap(), &value_repr, None);
+            ymd.append(parse_int(value_repr)?, value_repr, spans[idx].clone(), None);
 
-            if idx + 2 < len_l && !info.get_jump(&tokens[idx + 2]) {
+            if idx + 2 < len_l && !info.get_jump(tokens[idx + 2]) {
                 if let Ok(val) = tokens[idx + 2].parse::<i32>() {
-                    ymd.append(val, &tokens[idx + 2], None);
-                } else if let Some(val) = info.get_month(&tokens[idx + 2]) {
-                    ymd.append(val as i32, &tokens[idx + 2], Some(YMDLabel::Month));
+                    ymd.append(val, tokens[idx + 2], spans[idx + 2].clone(), None);
+                } else if let Some(val) = info.get_month(tokens[idx + 2]) {
+                    ymd.append(val as i32, tokens[idx + 2], spans[idx + 2].clone(), Some(YMDLabel::Month));
                 }
 
                 if idx + 3 < len_l && &tokens[idx + 3] == sep {
-                    if let Some(value) = info.get_month(&tokens[idx + 4]) {
-                        ymd.append(value as i32, &tokens[idx + 4], Some(YMDLabel::Month));
+                    if let Some(value) = tokens.get(idx + 4).and_then(|t| info.get_month(t)) {
+                        ymd.append(value as i32, tokens[idx + 4], spans[idx + 4].clone(), Some(YMDLabel::Month));
                     } else {
-                        ymd.append(tokens[idx + 4].parse::<i32>().unwrap(), &tokens[idx + 4], None);
+                        let value = integer_at(tokens, idx + 4)?;
+                        ymd.append(value, tokens[idx + 4], spans[idx + 4].clone(), None);
                     }
 
                     idx += 2;
@@ -1161,25 +2026,33 @@ impl Parser {
             }
 
             idx += 1
-        } else if idx + 1 >= len_l || info.get_jump(&tokens[idx + 1]) {
-            if idx + 2 < len_l && info.get_ampm(&tokens[idx + 2]).is_some() {
-                let hour = value.to_i64().unwrap() as i32;
-                let ampm = info.get_ampm(&tokens[idx + 2]).unwrap();
+        } else if idx + 1 >= len_l || info.get_jump(tokens[idx + 1]) {
+            // Named time further on ("12 noon", "7 in the evening") or earlier
+            // ("this evening at 7")
+            let named_time_follows = tokens[idx + 1..]
+                .iter()
+                .find(|t| !info.get_jump(t))
+                .map_or(false, |t| info.get_named_time(t).is_some());
+            let named_time_precedes = res.named_time.is_some()
+                && tokens[..idx].iter().rev().find(|&&t| t != " ").map_or(false, |t| t.eq_ignore_ascii_case("at"));
+
+            if let Some(ampm) = tokens.get(idx + 2).and_then(|t| info.get_ampm(t)) {
+                let hour = value.to_i32()?;
                 res.hour = Some(self.adjust_ampm(hour, ampm));
+            } else if (named_time_follows || named_time_precedes) && res.hour.is_none() && value.int < 24 {
+                res.hour = Some(value.to_i32()?);
             } else {
-                ymd.append(value.floor().to_i64().unwrap() as i32, &value_repr, None);
+                ymd.append(value.to_i32()?, value_repr, spans[idx].clone(), None);
             }
-        } else if info.get_ampm(&tokens[idx + 1]).is_some()
-            && (*ZERO <= value && value < *TWENTY_FOUR)
-        {
+        } else if let (Some(ampm), true) = (info.get_ampm(tokens[idx + 1]), value.int < 24) {
             // 12am
-            let hour = value.to_i64().unwrap() as i32;
-            res.hour = Some(self.adjust_ampm(hour, info.get_ampm(&tokens[idx + 1]).unwrap()));
+            let hour = value.to_i32()?;
+            res.hour = Some(self.adjust_ampm(hour, ampm));
             idx += 1;
-        } else if ymd.could_be_day(value.to_i64().unwrap() as i32) {
-            ymd.append(value.to_i64().unwrap() as i32, &value_repr, None);
+        } else if let Some(day) = value.to_i32().ok().filter(|day| ymd.could_be_day(*day)) {
+            ymd.append(day, value_repr, spans[idx].clone(), None);
         } else if !fuzzy {
-            return Err(ParseInternalError::ValueError("".to_owned()));
+            return Err(ErrorKind::UnrecognizedToken);
         }
 
         Ok(idx)
@@ -1195,39 +2068,39 @@ impl Parser {
         }
     }
 
-    fn parsems(&self, seconds_str: &str) -> Result<(i32, i32), ParseInternalError> {
+    fn parsems(&self, seconds_str: &str) -> ParseIResult<(i32, i32)> {
         if seconds_str.contains(".") {
             let split: Vec<&str> = seconds_str.split(".").collect();
             let (i, f): (&str, &str) = (split[0], split[1]);
 
-            let i_parse = i.parse::<i32>()?;
-            let f_parse = ljust(f, 6, '0').parse::<i32>()?;
+            let i_parse = parse_int(i)?;
+            let f_parse = parse_int(&ljust(f, 6, '0'))?;
             Ok((i_parse, f_parse))
         } else {
-            Ok((seconds_str.parse::<i32>()?, 0))
+            Ok((parse_int(seconds_str)?, 0))
         }
     }
 
     fn find_hms_index(
         &self,
         idx: usize,
-        tokens: &Vec<String>,
+        tokens: &[&str],
         info: &ParserInfo,
         allow_jump: bool,
     ) -> Option<usize> {
         let len_l = tokens.len();
         let mut hms_idx = None;
 
-        if idx + 1 < len_l && info.get_hms(&tokens[idx + 1]).is_some() {
+        if idx + 1 < len_l && info.get_hms(tokens[idx + 1]).is_some() {
             hms_idx = Some(idx + 1)
         } else if allow_jump && idx + 2 < len_l && tokens[idx + 1] == " "
-            && info.get_hms(&tokens[idx + 2]).is_some()
+            && info.get_hms(tokens[idx + 2]).is_some()
         {
             hms_idx = Some(idx + 2)
-        } else if idx > 0 && info.get_hms(&tokens[idx - 1]).is_some() {
+        } else if idx > 0 && info.get_hms(tokens[idx - 1]).is_some() {
             hms_idx = Some(idx - 1)
-        } else if len_l > 0 && idx > 0 && idx == len_l - 1 && tokens[idx - 1] == " "
-            && info.get_hms(&tokens[idx - 2]).is_some()
+        } else if idx > 1 && idx == len_l - 1 && tokens[idx - 1] == " "
+            && info.get_hms(tokens[idx - 2]).is_some()
         {
             hms_idx = Some(idx - 2)
         }
@@ -1238,72 +2111,71 @@ impl Parser {
     fn parse_hms(
         &self,
         idx: usize,
-        tokens: &Vec<String>,
+        tokens: &[&str],
         info: &ParserInfo,
         hms_index: Option<usize>,
     ) -> (usize, Option<usize>) {
-        if hms_index.is_none() {
-            (idx, None)
-        } else if hms_index.unwrap() > idx {
-            (
-                hms_index.unwrap(),
-                info.get_hms(&tokens[hms_index.unwrap()]),
-            )
-        } else {
-            (
-                idx,
-                info.get_hms(&tokens[hms_index.unwrap()]).map(|u| u + 1),
-            )
+        match hms_index {
+            None => (idx, None),
+            Some(hms_index) if hms_index > idx => (hms_index, info.get_hms(tokens[hms_index])),
+            Some(hms_index) => (idx, info.get_hms(tokens[hms_index]).map(|u| u + 1)),
         }
     }
 
-    fn assign_hms(&self, res: &mut ParsingResult, value_repr: &str, hms: usize) {
-        let value = self.to_decimal(value_repr);
+    fn assign_hms(&self, res: &mut ParsingResult, value_repr: &str, hms: usize) -> ParseIResult<()> {
+        let value = to_numeric(value_repr)?;
 
         if hms == 0 {
-            res.hour = Some(value.to_i64().unwrap() as i32);
-            if !close_to_integer(&value) {
-                // TODO: High probability of issues with rounding here.
-                res.minute = Some((*SIXTY * (value % *ONE)).to_i64().unwrap() as i32);
+            res.hour = Some(value.to_i32()?);
+            if !value.is_integer() {
+                res.minute = Some(value.fraction_in(60));
             }
         } else if hms == 1 {
-            let (min, sec) = self.parse_min_sec(value);
+            let (min, sec) = self.parse_min_sec(value)?;
             res.minute = Some(min);
             res.second = sec;
         } else if hms == 2 {
-            let (sec, micro) = self.parsems(value_repr).unwrap();
+            let (sec, micro) = self.parsems(value_repr)?;
             res.second = Some(sec);
             res.microsecond = Some(micro);
         }
-    }
 
-    fn to_decimal(&self, value: &str) -> Decimal {
-        // TODO: Justify unwrap
-        Decimal::from_str(value).unwrap()
+        Ok(())
     }
 
-    fn parse_min_sec(&self, value: Decimal) -> (i32, Option<i32>) {
-        let minute = value.floor().to_i64().unwrap() as i32;
+    fn parse_min_sec(&self, value: Numeric) -> ParseIResult<(i32, Option<i32>)> {
+        let minute = value.to_i32()?;
         let mut second = None;
 
-        let sec_remainder = value - value.floor();
-        if sec_remainder != *ZERO {
-            second = Some((*SIXTY * sec_remainder).floor().to_i64().unwrap() as i32);
+        if !value.is_integer() {
+            second = Some(value.fraction_in(60));
         }
 
-        (minute, second)
+        Ok((minute, second))
     }
 }
 
-fn close_to_integer(value: &Decimal) -> bool {
-    value % *ONE == *ZERO
+fn to_numeric(value: &str) -> ParseIResult<Numeric> {
+    Numeric::parse(value).ok_or(ErrorKind::InvalidNumber)
+}
+
+/// Span of `token[range]` within `span`, the span of `token`. Tokens that
+/// were normalized ("２００３") don't line up with the input, so those keep
+/// the whole span.
+fn sub_span(span: &Range<usize>, token: &str, range: Range<usize>) -> Range<usize> {
+    if span.end - span.start == token.len() {
+        span.start + range.start..span.start + range.end
+    } else {
+        span.clone()
+    }
 }
 
 fn ljust(s: &str, chars: usize, replace: char) -> String {
-    if s.len() >= chars {
-        s[..chars].to_owned()
+    let len = s.chars().count();
+    if len >= chars {
+        s.chars().take(chars).collect()
     } else {
-        format!("{}{}", s, replace.to_string().repeat(chars - s.len()))
+        format!("{}{}", s, replace.to_string().repeat(chars - len))
     }
 }
 
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use locale::cldr_data::LOCALES;
+use locale::jump;
+use parse_info;
+use Calendar;
+use ParserInfo;
+
+/// Vocabulary for one CLDR locale, as emitted by `build_cldr.py`. Names are
+/// already lowercased and stripped of punctuation.
+pub struct CldrLocale {
+    pub tag: &'static str,
+    pub months: [&'static [&'static str]; 12],
+    // Monday first
+    pub weekdays: [&'static [&'static str]; 7],
+    // Following a month number, for months named like 9月
+    pub month_markers: &'static [&'static str],
+    pub am: &'static [&'static str],
+    pub pm: &'static [&'static str],
+    // Pieces of multi-word day periods
+    pub jump: &'static [&'static str],
+    // Common era names, as `era_key`s
+    pub eras: &'static [&'static str],
+    pub dayfirst: bool,
+    pub yearfirst: bool,
+}
+
+/// Find the CLDR locale for a language tag, falling back to less specific
+/// tags ("pt-BR" to "pt") when there is no exact match.
+fn find(tag: &str) -> Option<&'static CldrLocale> {
+    let mut tag = tag.replace('_', "-");
+
+    loop {
+        if let Some(locale) = LOCALES.iter().find(|l| l.tag.eq_ignore_ascii_case(&tag)) {
+            return Some(locale);
+        }
+
+        match tag.rfind('-') {
+            Some(idx) => tag.truncate(idx),
+            None => return None,
+        }
+    }
+}
+
+pub fn parser_info(tag: &str) -> Option<ParserInfo> {
+    let locale = find(tag)?;
+
+    Some(ParserInfo {
+        jump: jump(locale.jump.to_vec()),
+        weekday: parse_info(locale.weekdays.iter().map(|w| w.to_vec()).collect()),
+        months: parse_info(locale.months.iter().map(|m| m.to_vec()).collect()),
+        ymd: parse_info(vec![vec![], locale.month_markers.to_vec(), vec![]]),
+        ampm: parse_info(vec![locale.am.to_vec(), locale.pm.to_vec()]),
+        pertain: parse_info(vec![vec![]]),
+        named_time: parse_info(vec![vec![]]),
+        relative_day: HashMap::new(),
+        eras: locale.eras.iter().map(|&era| (era.to_owned(), Calendar::Gregorian)).collect(),
+        dayfirst: locale.dayfirst,
+        yearfirst: locale.yearfirst,
+        ..ParserInfo::default()
+    }
+    .compiled())
+}
+
+/// Language tags of every locale compiled in.
+pub fn tags() -> Vec<&'static str> {
+    LOCALES.iter().map(|l| l.tag).collect()
+}
@@ -0,0 +1,171 @@
+// Generated by build_cldr.py from the CLDR snapshot in cldr/. Do not edit by hand.
+
+use locale::cldr::CldrLocale;
+
+pub static LOCALES: &[CldrLocale] = &[
+    CldrLocale {
+        tag: "de",
+        months: [
+            &["jan", "januar"],
+            &["feb", "februar"],
+            &["märz", "mär"],
+            &["apr", "april"],
+            &["mai"],
+            &["juni", "jun"],
+            &["juli", "jul"],
+            &["aug", "august"],
+            &["sept", "september", "sep"],
+            &["okt", "oktober"],
+            &["nov", "november"],
+            &["dez", "dezember"],
+        ],
+        weekdays: [
+            &["mo", "montag"],
+            &["di", "dienstag"],
+            &["mi", "mittwoch"],
+            &["do", "donnerstag"],
+            &["fr", "freitag"],
+            &["sa", "samstag"],
+            &["so", "sonntag"],
+        ],
+        month_markers: &[],
+        am: &["am"],
+        pm: &["pm"],
+        jump: &[],
+        eras: &["nchr"],
+        dayfirst: true,
+        yearfirst: false,
+    },
+    CldrLocale {
+        tag: "en",
+        months: [
+            &["jan", "january"],
+            &["feb", "february"],
+            &["mar", "march"],
+            &["apr", "april"],
+            &["may"],
+            &["jun", "june"],
+            &["jul", "july"],
+            &["aug", "august"],
+            &["sep", "september"],
+            &["oct", "october"],
+            &["nov", "november"],
+            &["dec", "december"],
+        ],
+        weekdays: [
+            &["mon", "monday"],
+            &["tue", "tuesday"],
+            &["wed", "wednesday"],
+            &["thu", "thursday"],
+            &["fri", "friday"],
+            &["sat", "saturday"],
+            &["sun", "sunday"],
+        ],
+        month_markers: &[],
+        am: &["am"],
+        pm: &["pm"],
+        jump: &[],
+        eras: &["ad", "annodomini"],
+        dayfirst: false,
+        yearfirst: false,
+    },
+    CldrLocale {
+        tag: "es",
+        months: [
+            &["ene", "enero"],
+            &["feb", "febrero"],
+            &["mar", "marzo"],
+            &["abr", "abril"],
+            &["may", "mayo"],
+            &["jun", "junio"],
+            &["jul", "julio"],
+            &["ago", "agosto"],
+            &["sept", "septiembre"],
+            &["oct", "octubre"],
+            &["nov", "noviembre"],
+            &["dic", "diciembre"],
+        ],
+        weekdays: [
+            &["lun", "lunes"],
+            &["mar", "martes"],
+            &["mié", "miércoles"],
+            &["jue", "jueves"],
+            &["vie", "viernes"],
+            &["sáb", "sábado"],
+            &["dom", "domingo"],
+        ],
+        month_markers: &[],
+        am: &["a"],
+        pm: &["p"],
+        jump: &["m"],
+        eras: &["dc", "despuésdecristo"],
+        dayfirst: true,
+        yearfirst: false,
+    },
+    CldrLocale {
+        tag: "fr",
+        months: [
+            &["janv", "janvier"],
+            &["févr", "février"],
+            &["mars"],
+            &["avr", "avril"],
+            &["mai"],
+            &["juin"],
+            &["juil", "juillet"],
+            &["août"],
+            &["sept", "septembre"],
+            &["oct", "octobre"],
+            &["nov", "novembre"],
+            &["déc", "décembre"],
+        ],
+        weekdays: [
+            &["lun", "lundi"],
+            &["mar", "mardi"],
+            &["mer", "mercredi"],
+            &["jeu", "jeudi"],
+            &["ven", "vendredi"],
+            &["sam", "samedi"],
+            &["dim", "dimanche"],
+        ],
+        month_markers: &[],
+        am: &["am"],
+        pm: &["pm"],
+        jump: &[],
+        eras: &["apjc", "aprèsjésuschrist"],
+        dayfirst: true,
+        yearfirst: false,
+    },
+    CldrLocale {
+        tag: "ja",
+        months: [
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+            &[],
+        ],
+        weekdays: [
+            &["月曜日"],
+            &["火曜日"],
+            &["水曜日"],
+            &["木曜日"],
+            &["金曜日"],
+            &["土曜日"],
+            &["日曜日"],
+        ],
+        month_markers: &["月"],
+        am: &["午前"],
+        pm: &["午後"],
+        jump: &[],
+        eras: &["西暦"],
+        dayfirst: false,
+        yearfirst: true,
+    },
+];
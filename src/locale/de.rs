@@ -0,0 +1,57 @@
+use locale::jump;
+use parse_hours;
+use parse_info;
+use parse_relative_days;
+use ParserInfo;
+
+pub fn info() -> ParserInfo {
+    ParserInfo {
+        jump: jump(vec!["am", "um", "den", "der", "im", "vom", "und", "uhr"]),
+        weekday: parse_info(vec![
+            vec!["mo", "montag"],
+            vec!["di", "dienstag"],
+            vec!["mi", "mittwoch"],
+            vec!["do", "donnerstag"],
+            vec!["fr", "freitag"],
+            vec!["sa", "samstag", "sonnabend"],
+            vec!["so", "sonntag"],
+        ]),
+        months: parse_info(vec![
+            vec!["jan", "januar", "jän", "jänner"],
+            vec!["feb", "februar"],
+            vec!["mär", "mrz", "märz", "maerz"],
+            vec!["apr", "april"],
+            vec!["mai"],
+            vec!["jun", "juni"],
+            vec!["jul", "juli"],
+            vec!["aug", "august"],
+            vec!["sep", "sept", "september"],
+            vec!["okt", "oktober"],
+            vec!["nov", "november"],
+            vec!["dez", "dezember"],
+        ]),
+        hms: parse_info(vec![
+            vec!["h", "std", "stunde", "stunden", "uhr"],
+            vec!["m", "min", "minute", "minuten"],
+            vec!["s", "sek", "sekunde", "sekunden"],
+        ]),
+        ampm: parse_info(vec![vec![]]),
+        pertain: parse_info(vec![vec![]]),
+        named_time: parse_hours(vec![
+            ("mitternacht", 0),
+            ("morgens", 9),
+            ("vormittag", 10),
+            ("vormittags", 10),
+            ("mittag", 12),
+            ("mittags", 12),
+            ("nachmittag", 15),
+            ("nachmittags", 15),
+            ("abend", 18),
+            ("abends", 18),
+            ("nachts", 21),
+        ]),
+        relative_day: parse_relative_days(vec![vec!["gestern"], vec!["heute"], vec!["morgen"]]),
+        dayfirst: true,
+        ..ParserInfo::default()
+    }
+}
@@ -0,0 +1,52 @@
+use locale::jump;
+use parse_hours;
+use parse_info;
+use parse_relative_days;
+use ParserInfo;
+
+pub fn info() -> ParserInfo {
+    ParserInfo {
+        jump: jump(vec!["de", "del", "el", "la", "las", "los", "a", "y", "en", "º"]),
+        // "mar" is left to March, as it is the more common abbreviation
+        weekday: parse_info(vec![
+            vec!["lun", "lunes"],
+            vec!["martes"],
+            vec!["mié", "mie", "miércoles", "miercoles"],
+            vec!["jue", "jueves"],
+            vec!["vie", "viernes"],
+            vec!["sáb", "sab", "sábado", "sabado"],
+            vec!["dom", "domingo"],
+        ]),
+        months: parse_info(vec![
+            vec!["ene", "enero"],
+            vec!["feb", "febrero"],
+            vec!["mar", "marzo"],
+            vec!["abr", "abril"],
+            vec!["may", "mayo"],
+            vec!["jun", "junio"],
+            vec!["jul", "julio"],
+            vec!["ago", "agosto"],
+            vec!["sep", "sept", "set", "septiembre", "setiembre"],
+            vec!["oct", "octubre"],
+            vec!["nov", "noviembre"],
+            vec!["dic", "diciembre"],
+        ]),
+        hms: parse_info(vec![
+            vec!["h", "hora", "horas"],
+            vec!["m", "min", "minuto", "minutos"],
+            vec!["s", "seg", "segundo", "segundos"],
+        ]),
+        ampm: parse_info(vec![vec!["am"], vec!["pm"]]),
+        pertain: parse_info(vec![vec!["de"]]),
+        named_time: parse_hours(vec![
+            ("medianoche", 0),
+            ("mediodía", 12),
+            ("mediodia", 12),
+            ("tarde", 15),
+            ("noche", 21),
+        ]),
+        relative_day: parse_relative_days(vec![vec!["ayer"], vec!["hoy"], vec!["mañana", "manana"]]),
+        dayfirst: true,
+        ..ParserInfo::default()
+    }
+}
@@ -0,0 +1,53 @@
+use locale::jump;
+use parse_hours;
+use parse_info;
+use parse_relative_days;
+use ParserInfo;
+
+pub fn info() -> ParserInfo {
+    ParserInfo {
+        // "aujourd'hui" is tokenized as "aujourd", "'", "hui"
+        jump: jump(vec!["le", "la", "à", "a", "au", "de", "du", "et", "en", "er", "vers", "hui"]),
+        weekday: parse_info(vec![
+            vec!["lun", "lundi"],
+            vec!["mar", "mardi"],
+            vec!["mer", "mercredi"],
+            vec!["jeu", "jeudi"],
+            vec!["ven", "vendredi"],
+            vec!["sam", "samedi"],
+            vec!["dim", "dimanche"],
+        ]),
+        months: parse_info(vec![
+            vec!["janv", "janvier"],
+            vec!["févr", "fevr", "fév", "fev", "février", "fevrier"],
+            vec!["mars"],
+            vec!["avr", "avril"],
+            vec!["mai"],
+            vec!["juin"],
+            vec!["juil", "juillet"],
+            vec!["août", "aout"],
+            vec!["sept", "septembre"],
+            vec!["oct", "octobre"],
+            vec!["nov", "novembre"],
+            vec!["déc", "dec", "décembre", "decembre"],
+        ]),
+        hms: parse_info(vec![
+            vec!["h", "heure", "heures"],
+            vec!["min", "minute", "minutes"],
+            vec!["s", "seconde", "secondes"],
+        ]),
+        ampm: parse_info(vec![vec![]]),
+        pertain: parse_info(vec![vec!["de"]]),
+        named_time: parse_hours(vec![
+            ("minuit", 0),
+            ("matin", 9),
+            ("midi", 12),
+            ("soir", 18),
+            ("soirée", 18),
+            ("nuit", 21),
+        ]),
+        relative_day: parse_relative_days(vec![vec!["hier"], vec!["aujourd"], vec!["demain"]]),
+        dayfirst: true,
+        ..ParserInfo::default()
+    }
+}
@@ -0,0 +1,55 @@
+use locale::jump;
+use parse_hours;
+use parse_info;
+use parse_relative_days;
+use ParserInfo;
+
+pub fn info() -> ParserInfo {
+    ParserInfo {
+        jump: jump(vec!["il", "l", "lo", "di", "del", "alle", "ore", "e", "a"]),
+        // "mar" is left to March, as it is the more common abbreviation
+        weekday: parse_info(vec![
+            vec!["lun", "lunedì", "lunedi"],
+            vec!["martedì", "martedi"],
+            vec!["mer", "mercoledì", "mercoledi"],
+            vec!["gio", "giovedì", "giovedi"],
+            vec!["ven", "venerdì", "venerdi"],
+            vec!["sab", "sabato"],
+            vec!["dom", "domenica"],
+        ]),
+        months: parse_info(vec![
+            vec!["gen", "gennaio"],
+            vec!["feb", "febbraio"],
+            vec!["mar", "marzo"],
+            vec!["apr", "aprile"],
+            vec!["mag", "maggio"],
+            vec!["giu", "giugno"],
+            vec!["lug", "luglio"],
+            vec!["ago", "agosto"],
+            vec!["set", "sett", "settembre"],
+            vec!["ott", "ottobre"],
+            vec!["nov", "novembre"],
+            vec!["dic", "dicembre"],
+        ]),
+        hms: parse_info(vec![
+            vec!["h"],
+            vec!["m", "min", "minuto", "minuti"],
+            vec!["s", "sec", "secondo", "secondi"],
+        ]),
+        ampm: parse_info(vec![vec![]]),
+        pertain: parse_info(vec![vec!["di"]]),
+        named_time: parse_hours(vec![
+            ("mezzanotte", 0),
+            ("mattina", 9),
+            ("mattino", 9),
+            ("mezzogiorno", 12),
+            ("pomeriggio", 15),
+            ("sera", 18),
+            ("stasera", 18),
+            ("notte", 21),
+        ]),
+        relative_day: parse_relative_days(vec![vec!["ieri"], vec!["oggi", "stasera"], vec!["domani"]]),
+        dayfirst: true,
+        ..ParserInfo::default()
+    }
+}
@@ -0,0 +1,39 @@
+use locale::jump;
+use parse_hours;
+use parse_info;
+use parse_relative_days;
+use ParserInfo;
+
+pub fn info() -> ParserInfo {
+    ParserInfo {
+        jump: jump(vec!["、", "。", "(", ")", "の", "に"]),
+        weekday: parse_info(vec![
+            vec!["月曜日", "月曜"],
+            vec!["火曜日", "火曜"],
+            vec!["水曜日", "水曜"],
+            vec!["木曜日", "木曜"],
+            vec!["金曜日", "金曜"],
+            vec!["土曜日", "土曜"],
+            vec!["日曜日", "日曜"],
+        ]),
+        // Months are numbers followed by 月
+        months: parse_info(vec![vec![]; 12]),
+        hms: parse_info(vec![vec!["時"], vec!["分"], vec!["秒"]]),
+        ymd: parse_info(vec![vec!["年"], vec!["月"], vec!["日"]]),
+        ampm: parse_info(vec![vec!["午前"], vec!["午後"]]),
+        ampm_prefix: true,
+        pertain: parse_info(vec![vec![]]),
+        named_time: parse_hours(vec![
+            ("深夜", 0),
+            ("朝", 9),
+            ("正午", 12),
+            ("昼", 12),
+            ("夕方", 18),
+            ("夜", 21),
+            ("今夜", 21),
+        ]),
+        relative_day: parse_relative_days(vec![vec!["昨日"], vec!["今日", "今夜"], vec!["明日"]]),
+        yearfirst: true,
+        ..ParserInfo::default()
+    }
+}
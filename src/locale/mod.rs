@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use parse_info;
+use ParserInfo;
+
+#[cfg(feature = "cldr")]
+pub mod cldr;
+#[cfg(feature = "cldr")]
+mod cldr_data;
+mod de;
+mod es;
+mod fr;
+mod it;
+mod ja;
+mod nl;
+mod pt;
+mod zh;
+
+/// Separators every locale skips over.
+const PUNCTUATION: [&str; 7] = [" ", ".", ",", ";", "-", "/", "'"];
+
+/// Jump table made of the common punctuation plus locale-specific words.
+fn jump(words: Vec<&str>) -> HashMap<String, usize> {
+    let mut all: Vec<&str> = PUNCTUATION.to_vec();
+    all.extend(words);
+    parse_info(vec![all])
+}
+
+/// `ParserInfo` for a BCP 47 style language tag ("fr", "de-AT", "pt_BR").
+/// Only the primary language subtag is considered.
+pub fn parser_info(tag: &str) -> Option<ParserInfo> {
+    let language = tag
+        .split(['-', '_'])
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+
+    let info = match language.as_str() {
+        "en" => return Some(ParserInfo::default()),
+        "de" => de::info(),
+        "es" => es::info(),
+        "fr" => fr::info(),
+        "it" => it::info(),
+        "ja" => ja::info(),
+        "nl" => nl::info(),
+        "pt" => pt::info(),
+        "zh" => zh::info(),
+        _ => return None,
+    };
+
+    // The hand-written locales are built on the default info, lexicon and all
+    Some(info.compiled())
+}
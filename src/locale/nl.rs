@@ -0,0 +1,51 @@
+use locale::jump;
+use parse_hours;
+use parse_info;
+use parse_relative_days;
+use ParserInfo;
+
+pub fn info() -> ParserInfo {
+    ParserInfo {
+        jump: jump(vec!["om", "op", "de", "het", "van", "en", "uur"]),
+        weekday: parse_info(vec![
+            vec!["ma", "maandag"],
+            vec!["di", "dinsdag"],
+            vec!["wo", "woensdag"],
+            vec!["do", "donderdag"],
+            vec!["vr", "vrijdag"],
+            vec!["za", "zaterdag"],
+            vec!["zo", "zondag"],
+        ]),
+        months: parse_info(vec![
+            vec!["jan", "januari"],
+            vec!["feb", "februari"],
+            vec!["mrt", "mar", "maart"],
+            vec!["apr", "april"],
+            vec!["mei"],
+            vec!["jun", "juni"],
+            vec!["jul", "juli"],
+            vec!["aug", "augustus"],
+            vec!["sep", "sept", "september"],
+            vec!["okt", "oktober"],
+            vec!["nov", "november"],
+            vec!["dec", "december"],
+        ]),
+        hms: parse_info(vec![
+            vec!["u", "uur"],
+            vec!["m", "min", "minuut", "minuten"],
+            vec!["s", "sec", "seconde", "seconden"],
+        ]),
+        ampm: parse_info(vec![vec![]]),
+        pertain: parse_info(vec![vec![]]),
+        named_time: parse_hours(vec![
+            ("middernacht", 0),
+            ("ochtend", 9),
+            ("middag", 12),
+            ("avond", 18),
+            ("nacht", 21),
+        ]),
+        relative_day: parse_relative_days(vec![vec!["gisteren"], vec!["vandaag"], vec!["morgen"]]),
+        dayfirst: true,
+        ..ParserInfo::default()
+    }
+}
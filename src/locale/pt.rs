@@ -0,0 +1,52 @@
+use locale::jump;
+use parse_hours;
+use parse_info;
+use parse_relative_days;
+use ParserInfo;
+
+pub fn info() -> ParserInfo {
+    ParserInfo {
+        jump: jump(vec!["de", "do", "da", "às", "as", "à", "a", "e", "o", "feira", "º"]),
+        // Weekdays are usually written "segunda-feira"; "feira" is a jump word.
+        // "mar" is left to March.
+        weekday: parse_info(vec![
+            vec!["seg", "segunda"],
+            vec!["ter", "terça", "terca"],
+            vec!["qua", "quarta"],
+            vec!["qui", "quinta"],
+            vec!["sex", "sexta"],
+            vec!["sáb", "sab", "sábado", "sabado"],
+            vec!["dom", "domingo"],
+        ]),
+        months: parse_info(vec![
+            vec!["jan", "janeiro"],
+            vec!["fev", "fevereiro"],
+            vec!["mar", "março", "marco"],
+            vec!["abr", "abril"],
+            vec!["mai", "maio"],
+            vec!["jun", "junho"],
+            vec!["jul", "julho"],
+            vec!["ago", "agosto"],
+            vec!["set", "setembro"],
+            vec!["out", "outubro"],
+            vec!["nov", "novembro"],
+            vec!["dez", "dezembro"],
+        ]),
+        hms: parse_info(vec![
+            vec!["h", "hora", "horas"],
+            vec!["m", "min", "minuto", "minutos"],
+            vec!["s", "seg", "segundo", "segundos"],
+        ]),
+        ampm: parse_info(vec![vec![]]),
+        pertain: parse_info(vec![vec!["de"]]),
+        named_time: parse_hours(vec![
+            ("manhã", 9),
+            ("manha", 9),
+            ("tarde", 15),
+            ("noite", 21),
+        ]),
+        relative_day: parse_relative_days(vec![vec!["ontem"], vec!["hoje"], vec!["amanhã", "amanha"]]),
+        dayfirst: true,
+        ..ParserInfo::default()
+    }
+}
@@ -0,0 +1,31 @@
+use locale::jump;
+use parse_hours;
+use parse_info;
+use parse_relative_days;
+use ParserInfo;
+
+pub fn info() -> ParserInfo {
+    ParserInfo {
+        jump: jump(vec!["、", "。", "(", ")", "的", "在"]),
+        weekday: parse_info(vec![
+            vec!["星期一", "周一", "週一", "礼拜一", "禮拜一"],
+            vec!["星期二", "周二", "週二", "礼拜二", "禮拜二"],
+            vec!["星期三", "周三", "週三", "礼拜三", "禮拜三"],
+            vec!["星期四", "周四", "週四", "礼拜四", "禮拜四"],
+            vec!["星期五", "周五", "週五", "礼拜五", "禮拜五"],
+            vec!["星期六", "周六", "週六", "礼拜六", "禮拜六"],
+            vec!["星期日", "星期天", "周日", "週日", "礼拜日", "禮拜日", "礼拜天", "禮拜天"],
+        ]),
+        // Months are numbers followed by 月
+        months: parse_info(vec![vec![]; 12]),
+        hms: parse_info(vec![vec!["时", "時", "点", "點"], vec!["分"], vec!["秒"]]),
+        ymd: parse_info(vec![vec!["年"], vec!["月"], vec!["日", "号", "號"]]),
+        ampm: parse_info(vec![vec!["上午", "早上"], vec!["下午", "晚上"]]),
+        ampm_prefix: true,
+        pertain: parse_info(vec![vec![]]),
+        named_time: parse_hours(vec![("午夜", 0), ("中午", 12), ("傍晚", 18)]),
+        relative_day: parse_relative_days(vec![vec!["昨天"], vec!["今天"], vec!["明天"]]),
+        yearfirst: true,
+        ..ParserInfo::default()
+    }
+}
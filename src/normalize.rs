@@ -0,0 +1,68 @@
+// Folding of non-ASCII digits and full-width forms to ASCII, so "２００３年"
+// and "٢٠٠٣" tokenize and parse like "2003年" and "2003".
+
+use std::char;
+
+/// Code points of the zero in every run of Unicode decimal digits
+/// (general category Nd), as of Unicode 14. Each run is 0 through 9 in order.
+const DIGIT_ZEROS: [u32; 66] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66,
+    0x0CE6, 0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946,
+    0x19D0, 0x1A80, 0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0,
+    0xA9F0, 0xAA50, 0xABF0, 0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0,
+    0x112F0, 0x11450, 0x114D0, 0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50,
+    0x11DA0, 0x16A60, 0x16AC0, 0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140,
+    0x1E2F0, 0x1E950, 0x1FBF0,
+];
+
+/// Value of a Unicode decimal digit in any script.
+pub fn digit_value(c: char) -> Option<u32> {
+    let code = c as u32;
+    let zero = match DIGIT_ZEROS.binary_search(&code) {
+        Ok(_) => return Some(0),
+        Err(0) => return None,
+        Err(i) => DIGIT_ZEROS[i - 1],
+    };
+
+    if code - zero < 10 {
+        Some(code - zero)
+    } else {
+        None
+    }
+}
+
+/// ASCII equivalent of `c` if it is a decimal digit, a full-width form
+/// (U+FF01 to U+FF5E) or the ideographic space; otherwise `c` unchanged.
+pub fn normalize_char(c: char) -> char {
+    if c.is_ascii() {
+        return c;
+    }
+
+    if let Some(value) = digit_value(c) {
+        return char::from_digit(value, 10).unwrap_or(c);
+    }
+
+    match c as u32 {
+        0xFF01..=0xFF5E => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        0x3000 => ' ',
+        _ => c,
+    }
+}
+
+mod test {
+
+    use normalize::normalize_char;
+
+    fn normalize(s: &str) -> String {
+        s.chars().map(normalize_char).collect()
+    }
+
+    #[test]
+    fn digits_and_full_width() {
+        assert_eq!(normalize("٢٠٠٣-٠٩-٢٥"), "2003-09-25");
+        assert_eq!(normalize("२००३"), "2003");
+        assert_eq!(normalize("２００３年９月２５日"), "2003年9月25日");
+        assert_eq!(normalize("１０：３６\u{3000}ＰＭ"), "10:36 PM");
+        assert_eq!(normalize("½ Ⅻ 三"), "½ Ⅻ 三");
+    }
+}
@@ -0,0 +1,267 @@
+use Token;
+
+fn cardinal(word: &str) -> Option<u32> {
+    let value = match word {
+        "zero" => 0,
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        "eleven" => 11,
+        "twelve" => 12,
+        "thirteen" => 13,
+        "fourteen" => 14,
+        "fifteen" => 15,
+        "sixteen" => 16,
+        "seventeen" => 17,
+        "eighteen" => 18,
+        "nineteen" => 19,
+        "twenty" => 20,
+        "thirty" => 30,
+        "forty" => 40,
+        "fifty" => 50,
+        "sixty" => 60,
+        "seventy" => 70,
+        "eighty" => 80,
+        "ninety" => 90,
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+fn ordinal(word: &str) -> Option<u32> {
+    let value = match word {
+        "first" => 1,
+        "second" => 2,
+        "third" => 3,
+        "fourth" => 4,
+        "fifth" => 5,
+        "sixth" => 6,
+        "seventh" => 7,
+        "eighth" => 8,
+        "ninth" => 9,
+        "tenth" => 10,
+        "eleventh" => 11,
+        "twelfth" => 12,
+        "thirteenth" => 13,
+        "fourteenth" => 14,
+        "fifteenth" => 15,
+        "sixteenth" => 16,
+        "seventeenth" => 17,
+        "eighteenth" => 18,
+        "nineteenth" => 19,
+        "twentieth" => 20,
+        "thirtieth" => 30,
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Suffix the tokenizer splits off a numeric ordinal ("23rd" -> "23", "rd").
+pub fn ordinal_suffix(value: u32) -> &'static str {
+    match (value % 10, value % 100) {
+        (_, 11) | (_, 12) | (_, 13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+pub fn is_ordinal_suffix(token: &str) -> bool {
+    matches!(token.to_lowercase().as_str(), "st" | "nd" | "rd" | "th")
+}
+
+struct Number {
+    value: u32,
+    ordinal: bool,
+    // Index of the last token that is part of the number
+    last: usize,
+}
+
+struct Words<'a> {
+    tokens: &'a [Token<'a>],
+}
+
+impl<'a> Words<'a> {
+    fn word(&self, idx: usize) -> Option<String> {
+        self.tokens.get(idx).map(|t| t.text.to_lowercase())
+    }
+
+    /// Index of the next token after `idx` that is not a space.
+    fn next(&self, idx: usize) -> Option<usize> {
+        (idx + 1..self.tokens.len()).find(|&i| self.tokens[i].text != " ")
+    }
+
+    /// A number below one hundred: "seven", "twenty-three", "thirty first".
+    fn small(&self, idx: usize) -> Option<Number> {
+        let word = self.word(idx)?;
+
+        if let Some(value) = ordinal(&word) {
+            return Some(Number { value, ordinal: true, last: idx });
+        }
+
+        let value = cardinal(&word)?;
+        if value >= 20 && value % 10 == 0 {
+            // twenty-three, thirty first
+            let sep = idx + 1;
+            if self.tokens.get(sep).map_or(false, |t| t.text == "-" || t.text == " ") {
+                if let Some(unit) = self.word(sep + 1) {
+                    if let Some(u) = cardinal(&unit).filter(|&u| 0 < u && u < 10) {
+                        return Some(Number { value: value + u, ordinal: false, last: sep + 1 });
+                    } else if let Some(u) = ordinal(&unit).filter(|&u| u < 10) {
+                        return Some(Number { value: value + u, ordinal: true, last: sep + 1 });
+                    }
+                }
+            }
+        }
+
+        Some(Number { value, ordinal: false, last: idx })
+    }
+
+    /// A full number starting at `idx`, including spoken years such as
+    /// "two thousand and three", "nineteen ninety-six" and "nineteen oh five".
+    fn number(&self, idx: usize) -> Option<Number> {
+        let first = self.small(idx)?;
+        if first.ordinal {
+            return Some(first);
+        }
+
+        let next = match self.next(first.last) {
+            Some(next) => next,
+            None => return Some(first),
+        };
+        let next_word = self.word(next).unwrap_or_default();
+
+        if next_word == "thousand" || next_word == "hundred" {
+            let scale = if next_word == "thousand" { 1000 } else { 100 };
+            let mut number = Number { value: first.value * scale, ordinal: false, last: next };
+
+            let mut rest = self.next(next);
+            if rest.and_then(|i| self.word(i)) == Some("and".to_owned()) {
+                rest = rest.and_then(|i| self.next(i));
+            }
+            if let Some(rest) = rest.and_then(|i| self.small(i)) {
+                number.value += rest.value;
+                number.ordinal = rest.ordinal;
+                number.last = rest.last;
+            }
+
+            Some(number)
+        } else if 13 <= first.value && first.value < 100 {
+            if next_word == "oh" || next_word == "o" {
+                // nineteen oh five
+                let unit = self.next(next).and_then(|i| self.small(i));
+                if let Some(unit) = unit.filter(|u| u.value < 10 && !u.ordinal) {
+                    return Some(Number { value: first.value * 100 + unit.value, ordinal: false, last: unit.last });
+                }
+            } else if let Some(second) = self.small(next).filter(|s| s.value >= 10 && !s.ordinal) {
+                // nineteen ninety-six
+                return Some(Number { value: first.value * 100 + second.value, ordinal: false, last: second.last });
+            }
+
+            Some(first)
+        } else {
+            Some(first)
+        }
+    }
+}
+
+/// Replace spelled-out English numbers in `tokens` with the digit tokens the
+/// tokenizer would have produced, so "the twenty-third of May" parses exactly
+/// like "the 23rd of May". Ordinals keep their suffix as a separate token;
+/// both span all the words of the number.
+pub fn replace_number_words(tokens: Vec<Token>) -> Vec<Token> {
+    let needs_replacing = tokens.iter().any(|t| {
+        let t = t.text.to_lowercase();
+        cardinal(&t).is_some() || ordinal(&t).is_some()
+    });
+    if !needs_replacing {
+        return tokens;
+    }
+
+    let mut replaced = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+
+    {
+        let words = Words { tokens: &tokens };
+
+        while i < tokens.len() {
+            // "5 second" is a duration, not an ordinal
+            let after_number = replaced
+                .iter()
+                .rev()
+                .find(|t: &&Token| t.text != " ")
+                .map_or(false, |t| t.text.chars().all(|c| c.is_ascii_digit()));
+
+            match words.number(i) {
+                Some(ref number) if !(after_number && words.word(i) == Some("second".to_owned())) => {
+                    let span = tokens[i].span.start..tokens[number.last].span.end;
+                    replaced.push(Token::new(number.value.to_string(), span.clone()));
+                    if number.ordinal {
+                        replaced.push(Token::new(ordinal_suffix(number.value), span));
+                    }
+                    i = number.last + 1;
+                }
+                _ => {
+                    replaced.push(tokens[i].clone());
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    replaced
+}
+
+mod test {
+
+    use numbers::replace_number_words;
+    use tokenize_spans;
+
+    fn replace(s: &str) -> Vec<String> {
+        replace_number_words(tokenize_spans(s))
+            .into_iter()
+            .map(|t| t.text.into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn spelled_out_days() {
+        assert_eq!(replace("twenty-third"), vec!["23", "rd"]);
+        assert_eq!(replace("the thirty first"), vec!["the", " ", "31", "st"]);
+        assert_eq!(replace("Twelfth of May"), vec!["12", "th", " ", "of", " ", "May"]);
+        assert_eq!(replace("seven"), vec!["7"]);
+    }
+
+    #[test]
+    fn spelled_out_years() {
+        assert_eq!(replace("two thousand three"), vec!["2003"]);
+        assert_eq!(replace("two thousand and three"), vec!["2003"]);
+        assert_eq!(replace("nineteen ninety-six"), vec!["1996"]);
+        assert_eq!(replace("nineteen oh five"), vec!["1905"]);
+        assert_eq!(replace("twenty twenty-three"), vec!["2023"]);
+        assert_eq!(replace("nineteen hundred"), vec!["1900"]);
+    }
+
+    #[test]
+    fn durations_are_untouched() {
+        assert_eq!(replace("36 m 5 second"), vec!["36", " ", "m", " ", "5", " ", "second"]);
+        assert_eq!(replace("10:36"), vec!["10", ":", "36"]);
+    }
+
+    #[test]
+    fn spans_cover_the_words() {
+        let tokens = replace_number_words(tokenize_spans("May twenty-third"));
+        assert_eq!(tokens[2].span, 4..16);
+        assert_eq!(tokens[3].span, 4..16);
+    }
+}
@@ -0,0 +1,83 @@
+use ErrorKind;
+use ParseIResult;
+
+const NANOS_PER_UNIT: u64 = 1_000_000_000;
+
+/// The value of a number token such as "10" or "10.5": its integer part and
+/// its fraction in billionths, so fractions of an hour or minute convert
+/// exactly down to the nanosecond.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Numeric {
+    pub int: u64,
+    pub nanos: u32,
+}
+
+impl Numeric {
+    /// Read ASCII digits with an optional fraction after a period. Fraction
+    /// digits past the ninth are dropped, like every other remainder here,
+    /// and integer parts too large for a `u64` saturate, which no date or
+    /// time component accepts anyway.
+    pub fn parse(s: &str) -> Option<Numeric> {
+        let (int, fraction) = match s.find('.') {
+            Some(dot) => (&s[..dot], Some(&s[dot + 1..])),
+            None => (s, None),
+        };
+
+        let all_digits = |digits: &str| !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+        if !all_digits(int) || !fraction.map_or(true, all_digits) {
+            return None;
+        }
+
+        let int = int.bytes().fold(0u64, |n, b| {
+            n.saturating_mul(10).saturating_add(u64::from(b - b'0'))
+        });
+        let digit = |i: usize| fraction.and_then(|f| f.as_bytes().get(i)).map_or(0, |b| u32::from(b - b'0'));
+        let nanos = (0..9).fold(0, |nanos, i| nanos * 10 + digit(i));
+
+        Some(Numeric { int, nanos })
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.nanos == 0
+    }
+
+    /// Integer part, if it fits.
+    pub fn to_i32(self) -> ParseIResult<i32> {
+        if self.int <= i32::MAX as u64 {
+            Ok(self.int as i32)
+        } else {
+            Err(ErrorKind::InvalidNumber)
+        }
+    }
+
+    /// Whole number of `units` in the fraction, e.g. minutes in the
+    /// fraction of an hour for 60.
+    pub fn fraction_in(self, units: u32) -> i32 {
+        (u64::from(self.nanos) * u64::from(units) / NANOS_PER_UNIT) as i32
+    }
+}
+
+mod test {
+
+    use numeric::Numeric;
+
+    #[test]
+    fn parse() {
+        assert_eq!(Numeric::parse("2003"), Some(Numeric { int: 2003, nanos: 0 }));
+        assert_eq!(Numeric::parse("10.5"), Some(Numeric { int: 10, nanos: 500_000_000 }));
+        assert_eq!(Numeric::parse("0.0000000019"), Some(Numeric { int: 0, nanos: 1 }));
+        assert_eq!(Numeric::parse("1.9999999999"), Some(Numeric { int: 1, nanos: 999_999_999 }));
+        assert_eq!(Numeric::parse("99999999999999999999999").map(|n| n.int), Some(u64::MAX));
+        for s in &["", ".", "5.", ".5", "1.2.3", "-5", "1e5", "１２", "Sep"] {
+            assert_eq!(Numeric::parse(s), None, "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(Numeric::parse("1.1").unwrap().fraction_in(60), 6);
+        assert_eq!(Numeric::parse("10.75").unwrap().fraction_in(60), 45);
+        assert_eq!(Numeric::parse("2.999999999").unwrap().fraction_in(60), 59);
+        assert!(Numeric::parse("24.0").unwrap().is_integer());
+    }
+}
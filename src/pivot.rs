@@ -0,0 +1,63 @@
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+/// How two-digit years ("03", "'69") are expanded to a full year, relative
+/// to the reference year of the `ParserInfo`. Only applies to Gregorian
+/// years; other calendars take two-digit years as written.
+///
+/// ```
+/// use dtparse::YearPivot;
+///
+/// assert_eq!(YearPivot::SlidingWindow.expand(67, 2018), Some(2067));
+/// assert_eq!(YearPivot::SlidingWindow.expand(68, 2018), Some(1968));
+/// assert_eq!(YearPivot::FixedPivot(1930).expand(29, 2018), Some(2029));
+/// assert_eq!(YearPivot::FixedPivot(1930).expand(30, 2018), Some(1930));
+/// assert_eq!(YearPivot::PastOnly.expand(19, 2018), Some(1919));
+/// assert_eq!(YearPivot::FutureOnly.expand(17, 2018), Some(2117));
+/// assert_eq!(YearPivot::Reject.expand(18, 2018), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum YearPivot {
+    /// Within 50 years either side of the reference year. The default,
+    /// matching dateutil.
+    #[default]
+    SlidingWindow,
+    /// Within the hundred years starting at the given year, whatever the
+    /// reference year: with `FixedPivot(1930)`, 00-29 are 2000-2029 and
+    /// 30-99 are 1930-1999.
+    FixedPivot(i32),
+    /// The latest year no later than the reference year, e.g. for
+    /// birthdates.
+    PastOnly,
+    /// The earliest year no earlier than the reference year, e.g. for
+    /// expiry dates.
+    FutureOnly,
+    /// Two-digit years are an error (`ErrorKind::InvalidYear`).
+    Reject,
+}
+
+impl YearPivot {
+    /// The full year `year` (0-99) stands for when parsed in `reference`,
+    /// or `None` under `Reject`.
+    pub fn expand(&self, year: i32, reference: i32) -> Option<i32> {
+        match *self {
+            YearPivot::SlidingWindow => {
+                let year = year + reference / 100 * 100;
+                if year >= reference + 50 {
+                    Some(year - 100)
+                } else if year < reference - 50 {
+                    Some(year + 100)
+                } else {
+                    Some(year)
+                }
+            }
+            YearPivot::FixedPivot(first) => Some(first + (year - first).rem_euclid(100)),
+            YearPivot::PastOnly => Some(reference - (reference - year).rem_euclid(100)),
+            YearPivot::FutureOnly => Some(reference + (year - reference).rem_euclid(100)),
+            YearPivot::Reject => None,
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use chrono::Datelike;
+use serde::de::Error;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use calendar::era_key;
+use Calendar;
+use Clock;
+use ParserInfo;
+use SystemClock;
+use YearPivot;
+
+/// Serialized form of a `ParserInfo`; see the `ParserInfo` docs for the
+/// schema. Missing fields take their value from `ParserInfo::default()`.
+#[derive(Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Schema {
+    dayfirst: bool,
+    yearfirst: bool,
+    // Left out unless set with `reference_year`, so loaded infos take it
+    // from their clock
+    #[serde(skip_serializing_if = "Option::is_none")]
+    year: Option<i32>,
+    year_pivot: YearPivot,
+    calendar: Calendar,
+    ampm_prefix: bool,
+    jump: Vec<String>,
+    weekday: Vec<Vec<String>>,
+    months: Vec<Vec<String>>,
+    hms: Vec<Vec<String>>,
+    ymd: Vec<Vec<String>>,
+    ampm: Vec<Vec<String>>,
+    utczone: Vec<String>,
+    pertain: Vec<String>,
+    named_time: BTreeMap<String, u32>,
+    relative_day: BTreeMap<String, i64>,
+    tzoffset: BTreeMap<String, i32>,
+    eras: BTreeMap<String, Calendar>,
+}
+
+impl Default for Schema {
+    fn default() -> Self {
+        Schema::from(&ParserInfo::default())
+    }
+}
+
+impl From<&ParserInfo> for Schema {
+    fn from(info: &ParserInfo) -> Self {
+        Schema {
+            dayfirst: info.dayfirst,
+            yearfirst: info.yearfirst,
+            year: if info.year_overridden { Some(info.year) } else { None },
+            year_pivot: info.year_pivot,
+            calendar: info.calendar,
+            ampm_prefix: info.ampm_prefix,
+            jump: words(&info.jump),
+            weekday: groups(&info.weekday, 7),
+            months: groups(&info.months, 12),
+            hms: groups(&info.hms, 3),
+            ymd: groups(&info.ymd, 3),
+            ampm: groups(&info.ampm, 2),
+            utczone: words(&info.utczone),
+            pertain: words(&info.pertain),
+            named_time: info.named_time.iter().map(|(k, v)| (k.clone(), *v as u32)).collect(),
+            relative_day: info.relative_day.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            tzoffset: info.tzoffset.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            eras: info.eras.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+        }
+    }
+}
+
+impl Schema {
+    fn into_info(self) -> Result<ParserInfo, String> {
+        if let Some((name, hour)) = self.named_time.iter().find(|&(_, hour)| *hour > 23) {
+            return Err(format!("named_time \"{}\" has invalid hour {}", name, hour));
+        }
+
+        let year = self.year.unwrap_or_else(|| SystemClock.now().year());
+
+        Ok(ParserInfo {
+            jump: table(vec![self.jump]),
+            weekday: table(expect_len("weekday", self.weekday, 7)?),
+            months: table(expect_len("months", self.months, 12)?),
+            hms: table(expect_len("hms", self.hms, 3)?),
+            ymd: table(expect_len("ymd", self.ymd, 3)?),
+            ampm: table(expect_len("ampm", self.ampm, 2)?),
+            utczone: table(vec![self.utczone]),
+            pertain: table(vec![self.pertain]),
+            tzoffset: lowercase_keys(self.tzoffset),
+            named_time: lowercase_keys(self.named_time)
+                .into_iter()
+                .map(|(k, v)| (k, v as usize))
+                .collect(),
+            relative_day: lowercase_keys(self.relative_day),
+            eras: self.eras.into_iter().map(|(k, v)| (era_key(&k), v)).collect(),
+            ampm_prefix: self.ampm_prefix,
+            calendar: self.calendar,
+            dayfirst: self.dayfirst,
+            yearfirst: self.yearfirst,
+            year,
+            century: year / 100 * 100,
+            year_overridden: self.year.is_some(),
+            year_pivot: self.year_pivot,
+            clock: Arc::new(SystemClock),
+            lexicon: Arc::default(),
+        }
+        .compiled())
+    }
+}
+
+impl Serialize for ParserInfo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Schema::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ParserInfo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Schema::deserialize(deserializer)?
+            .into_info()
+            .map_err(D::Error::custom)
+    }
+}
+
+fn words(table: &HashMap<String, usize>) -> Vec<String> {
+    let mut words: Vec<String> = table.keys().cloned().collect();
+    words.sort();
+    words
+}
+
+fn groups(table: &HashMap<String, usize>, len: usize) -> Vec<Vec<String>> {
+    let mut groups = vec![Vec::new(); len];
+    for (word, &i) in table {
+        if i < len {
+            groups[i].push(word.clone());
+        }
+    }
+    for group in groups.iter_mut() {
+        group.sort();
+    }
+    groups
+}
+
+fn expect_len(field: &str, groups: Vec<Vec<String>>, len: usize) -> Result<Vec<Vec<String>>, String> {
+    if groups.len() == len {
+        Ok(groups)
+    } else {
+        Err(format!("{} must have {} lists of words, found {}", field, len, groups.len()))
+    }
+}
+
+fn table(groups: Vec<Vec<String>>) -> HashMap<String, usize> {
+    let mut table = HashMap::new();
+    for (i, group) in groups.into_iter().enumerate() {
+        for word in group {
+            table.insert(word.to_lowercase(), i);
+        }
+    }
+    table
+}
+
+fn lowercase_keys<V>(map: BTreeMap<String, V>) -> HashMap<String, V> {
+    map.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect()
+}
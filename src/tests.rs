@@ -1,8 +1,579 @@
-use ParseError;
+
+use chrono::Datelike;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Timelike;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use BusinessCalendar;
+use Calendar;
+use Component;
+use FixedClock;
+use HolidayRule;
+use HolidaySet;
+use ErrorKind;
+use InvalidVocabulary;
+use Parser;
+use ParserInfo;
+use Vocabulary;
+use Warning;
+use YearPivot;
 use parse;
+use tokenize_spans;
 
 #[test]
 fn test_fuzz() {
 
-    assert_eq!(parse("\x2D\x38\x31\x39\x34\x38\x34"), Err(ParseError::InvalidMonth));
-}
\ No newline at end of file
+    assert_eq!(parse("\x2D\x38\x31\x39\x34\x38\x34").unwrap_err().kind(), ErrorKind::InvalidMonth);
+}
+
+#[test]
+fn test_malformed_input() {
+    assert!(parse("Jan-").is_err());
+    assert!(parse("2003-09-").is_err());
+    assert!(parse("10:49:x").is_err());
+    assert_eq!(parse("10:60").unwrap_err().kind(), ErrorKind::InvalidMinute);
+    assert_eq!(parse("Sep 25 2003 10:00 +9999").unwrap_err().kind(), ErrorKind::TimezoneUnsupported);
+    assert_eq!(parse("Feb 29 2000").map(|r| r.0), Ok(NaiveDate::from_ymd_opt(2000, 2, 29).unwrap().and_hms_opt(0, 0, 0).unwrap()));
+}
+
+#[test]
+fn test_leap_years() {
+    // Days past the end of the month are clamped to it, with a warning
+    let feb29 = |s: &str| {
+        let outcome = Parser::default()
+            .parse_outcome(s, None, None, false, false, None, false, HashMap::new())
+            .expect(s);
+        let clamped = outcome.warnings.iter().any(|w| matches!(*w, Warning::DayClamped { from: 29, to: 28, .. }));
+        (outcome.datetime.date(), clamped)
+    };
+    let date = |year, day| NaiveDate::from_ymd_opt(year, 2, day).unwrap();
+
+    for &(s, year) in [("Feb 29 2000", 2000), ("2000-02-29", 2000), ("29/02/2000", 2000), ("Feb 29 2004", 2004)].iter() {
+        assert_eq!(feb29(s), (date(year, 29), false), "{}", s);
+    }
+    for &(s, year) in [("Feb 29 1900", 1900), ("1900-02-29", 1900), ("29/02/1900", 1900), ("Feb 29 2003", 2003)].iter() {
+        assert_eq!(feb29(s), (date(year, 28), true), "{}", s);
+    }
+}
+
+#[test]
+fn test_token_spans() {
+    let spans = |s: &str| -> Vec<(String, Range<usize>)> {
+        tokenize_spans(s).into_iter().map(|t| (t.text.into_owned(), t.span)).collect()
+    };
+
+    assert_eq!(spans("Sep 25"), vec![("Sep".to_owned(), 0..3), (" ".to_owned(), 3..4), ("25".to_owned(), 4..6)]);
+    assert_eq!(spans("a.m."), vec![("a".to_owned(), 0..1), (".".to_owned(), 1..2), ("m".to_owned(), 2..3), (".".to_owned(), 3..4)]);
+    assert_eq!(spans("２５日"), vec![("25".to_owned(), 0..6), ("日".to_owned(), 6..9)]);
+}
+
+#[test]
+fn test_tokens_borrow() {
+    let copied = |s: &str| -> Vec<String> {
+        tokenize_spans(s)
+            .into_iter()
+            .filter_map(|t| match t.text {
+                Cow::Owned(text) => Some(text),
+                Cow::Borrowed(_) => None,
+            })
+            .collect()
+    };
+
+    // Only tokens whose text differs from the input are copied
+    assert_eq!(copied("Sep 25 2003 10:49:41.5 a.m."), Vec::<String>::new());
+    assert_eq!(copied("２５日\t10,5"), vec!["25".to_owned(), "10.5".to_owned()]);
+}
+
+#[test]
+fn test_error_positions() {
+    let error = |s: &str| {
+        let err = parse(s).unwrap_err();
+        (err.kind(), err.token().to_owned(), err.span())
+    };
+
+    assert_eq!(error("Sep 25 2003 blah"), (ErrorKind::UnrecognizedToken, "blah".to_owned(), 12..16));
+    assert_eq!(error("Jan-"), (ErrorKind::UnexpectedEnd, "".to_owned(), 4..4));
+    assert_eq!(error("Feb 0 2003"), (ErrorKind::InvalidDay, "0".to_owned(), 4..5));
+    assert_eq!(error("20031325"), (ErrorKind::InvalidMonth, "13".to_owned(), 4..6));
+    assert_eq!(error("2003-13-25"), (ErrorKind::InvalidMonth, "13".to_owned(), 5..7));
+    assert_eq!(error("Sep 25 2003 10:60"), (ErrorKind::InvalidMinute, "10:60".to_owned(), 12..17));
+    assert_eq!(error("Sep 25 2003 10:00 +9999"), (ErrorKind::TimezoneUnsupported, "+9999".to_owned(), 18..23));
+    assert_eq!(error("1 2 3 4"), (ErrorKind::AmbiguousDate, "1 2 3 4".to_owned(), 0..7));
+    assert_eq!(error("2003-１３-25"), (ErrorKind::InvalidMonth, "１３".to_owned(), 5..11));
+
+    let err = parse("Sep 25 2003 blah").unwrap_err();
+    assert_eq!(err.to_string(), "unrecognized token \"blah\" at 12..16");
+    assert_eq!(err.diagnostic(), "unrecognized token \"blah\" at 12..16\nSep 25 2003 blah\n            ^^^^");
+}
+
+fn parse_with(parser: &mut Parser, timestr: &str) -> NaiveDateTime {
+    let default = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    parser
+        .parse(timestr, None, None, false, false, Some(&default), false, HashMap::new())
+        .expect(timestr)
+        .0
+}
+
+#[test]
+fn test_holidays() {
+    let mut parser = Parser::default();
+    parser.add_holiday_calendar(HolidaySet::us());
+
+    assert_eq!(parse_with(&mut parser, "Christmas 2003"), NaiveDate::from_ymd_opt(2003, 12, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "Thanksgiving"), NaiveDate::from_ymd_opt(2003, 11, 27).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "New Year's Eve at 11pm"), NaiveDate::from_ymd_opt(2003, 12, 31).unwrap().and_hms_opt(23, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "Christmas Eve 2004"), NaiveDate::from_ymd_opt(2004, 12, 24).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "Memorial Day, 2018 10:00"), NaiveDate::from_ymd_opt(2018, 5, 28).unwrap().and_hms_opt(10, 0, 0).unwrap());
+}
+
+#[test]
+fn test_custom_holidays() {
+    let mut corporate = HolidaySet::new();
+    corporate.insert("Founders' Day", HolidayRule::Fixed { month: 3, day: 9 });
+
+    let mut parser = Parser::default();
+    parser.add_holiday_calendar(corporate);
+    parser.add_holiday_calendar(HolidaySet::uk());
+
+    assert_eq!(parse_with(&mut parser, "Founders Day 2010"), NaiveDate::from_ymd_opt(2010, 3, 9).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "Easter Monday 2003"), NaiveDate::from_ymd_opt(2003, 4, 21).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "Boxing Day"), NaiveDate::from_ymd_opt(2003, 12, 26).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert!(Parser::default().parse("Boxing Day", None, None, false, false, None, false, HashMap::new()).is_err());
+}
+
+#[test]
+fn test_business_days() {
+    let mut calendar = BusinessCalendar::new();
+    calendar.add_holiday(NaiveDate::from_ymd_opt(2003, 10, 1).unwrap());
+
+    let mut parser = Parser::default();
+    parser.set_business_calendar(calendar);
+
+    assert_eq!(parse_with(&mut parser, "T+3"), NaiveDate::from_ymd_opt(2003, 9, 30).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "T+4"), NaiveDate::from_ymd_opt(2003, 10, 2).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "T-1"), NaiveDate::from_ymd_opt(2003, 9, 24).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "3 business days after 2003-09-25"), NaiveDate::from_ymd_opt(2003, 9, 30).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "2 working days before Sep 29 2003 10:00"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(10, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "next business day"), NaiveDate::from_ymd_opt(2003, 9, 26).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "next business day after 2003-09-26"), NaiveDate::from_ymd_opt(2003, 9, 29).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+    // Offsets past the range of dates fail without counting them out
+    for input in ["T+9223372036854775807 T+1", "T+999999999"].iter() {
+        let err = parser.parse(input, None, None, false, false, None, false, HashMap::new()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidDay, "{}", input);
+    }
+
+    // Without a calendar, the same input is ISO-like and parses as before
+    assert_eq!(parse_with(&mut Parser::default(), "2003-09-25T10"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(10, 0, 0).unwrap());
+}
+
+#[test]
+fn test_spelled_out_numbers() {
+    let mut parser = Parser::default();
+
+    assert_eq!(parse_with(&mut parser, "the twenty-third of May"), NaiveDate::from_ymd_opt(2003, 5, 23).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "first of June two thousand three"), NaiveDate::from_ymd_opt(2003, 6, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "March fifth, nineteen ninety-six"), NaiveDate::from_ymd_opt(1996, 3, 5).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "third Tuesday in November"), NaiveDate::from_ymd_opt(2003, 11, 18).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "2nd Monday of October 2004"), NaiveDate::from_ymd_opt(2004, 10, 11).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "last Friday of February 2008"), NaiveDate::from_ymd_opt(2008, 2, 29).unwrap().and_hms_opt(0, 0, 0).unwrap());
+}
+
+#[test]
+fn test_named_times() {
+    let mut parser = Parser::default();
+
+    assert_eq!(parse_with(&mut parser, "noon"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(12, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "midnight"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "12 noon"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(12, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "12 midnight"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "tomorrow morning"), NaiveDate::from_ymd_opt(2003, 9, 26).unwrap().and_hms_opt(9, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "this evening"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(18, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "yesterday at noon"), NaiveDate::from_ymd_opt(2003, 9, 24).unwrap().and_hms_opt(12, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "7 in the evening"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(19, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "this evening at 7"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(19, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "tomorrow morning at 7:30"), NaiveDate::from_ymd_opt(2003, 9, 26).unwrap().and_hms_opt(7, 30, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "Sep 30 2003 noon"), NaiveDate::from_ymd_opt(2003, 9, 30).unwrap().and_hms_opt(12, 0, 0).unwrap());
+
+    let mut info = ParserInfo::default();
+    info.set_named_time("evening", 20).unwrap();
+    assert_eq!(
+        info.set_named_time("evening", 24),
+        Err(InvalidVocabulary { category: Vocabulary::NamedTime, value: 24 })
+    );
+    assert_eq!(parse_with(&mut Parser::new(info), "tomorrow evening"), NaiveDate::from_ymd_opt(2003, 9, 26).unwrap().and_hms_opt(20, 0, 0).unwrap());
+}
+
+#[test]
+fn test_clock_phrases() {
+    let mut parser = Parser::default();
+
+    assert_eq!(parse_with(&mut parser, "half past three"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(3, 30, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "quarter to five pm"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(16, 45, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "ten o'clock"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(10, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "twenty past 9"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(9, 20, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "Sep 26 at a quarter past ten in the evening"), NaiveDate::from_ymd_opt(2003, 9, 26).unwrap().and_hms_opt(22, 15, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "quarter to one am"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 45, 0).unwrap());
+
+    // Numbers joined by "of" are still dates
+    assert_eq!(parse_with(&mut parser, "1 of 2 2003"), NaiveDate::from_ymd_opt(2003, 1, 2).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    let parse = |s: &str| Parser::default().parse(s, None, None, false, false, None, false, HashMap::new());
+    assert_eq!(parse("25 of 10").unwrap_err().kind(), ErrorKind::InvalidMonth);
+    assert_eq!(parse("Sep 25 2003 1 of 2").unwrap_err().kind(), ErrorKind::AmbiguousDate);
+}
+
+#[test]
+fn test_locales() {
+    let locale = |tag: &str| Parser::new(ParserInfo::for_locale(tag).unwrap());
+    let sep25 = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap();
+
+    let mut fr = locale("fr");
+    assert_eq!(parse_with(&mut fr, "jeudi 25 septembre 2003 à 10h36"), sep25.and_hms_opt(10, 36, 0).unwrap());
+    assert_eq!(parse_with(&mut fr, "3 févr. 2003"), NaiveDate::from_ymd_opt(2003, 2, 3).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut fr, "le 1er août 2003"), NaiveDate::from_ymd_opt(2003, 8, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut fr, "10/09/2003"), NaiveDate::from_ymd_opt(2003, 9, 10).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut fr, "demain midi"), NaiveDate::from_ymd_opt(2003, 9, 26).unwrap().and_hms_opt(12, 0, 0).unwrap());
+
+    let mut de = locale("de-AT");
+    assert_eq!(parse_with(&mut de, "am 25. Sept. 2003 um 10:36 Uhr"), sep25.and_hms_opt(10, 36, 0).unwrap());
+    assert_eq!(parse_with(&mut de, "Donnerstag, 3. Mär 2005"), NaiveDate::from_ymd_opt(2005, 3, 3).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut de, "morgen abends"), NaiveDate::from_ymd_opt(2003, 9, 26).unwrap().and_hms_opt(18, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut de, "Do., 25. Sept. 2003"), sep25.and_hms_opt(0, 0, 0).unwrap());
+    // Only the standard abbreviations: "die" (the) and "mit" (with) aren't weekdays
+    let info = ParserInfo::for_locale("de").unwrap();
+    assert_eq!((info.get_weekday("Di"), info.get_weekday("die"), info.get_weekday("mit")), (Some(1), None, None));
+
+    let mut es = locale("es");
+    assert_eq!(parse_with(&mut es, "jueves, 25 de septiembre de 2003"), sep25.and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut es, "3 mar 2004"), NaiveDate::from_ymd_opt(2004, 3, 3).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+    let mut it = locale("it");
+    assert_eq!(parse_with(&mut it, "giovedì 25 settembre 2003 alle 10:36"), sep25.and_hms_opt(10, 36, 0).unwrap());
+
+    let mut pt = locale("pt_BR");
+    assert_eq!(parse_with(&mut pt, "quinta-feira, 25 de setembro de 2003"), sep25.and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut pt, "25 set. 2003 às 10:36"), sep25.and_hms_opt(10, 36, 0).unwrap());
+
+    let mut nl = locale("nl");
+    assert_eq!(parse_with(&mut nl, "donderdag 25 sept. 2003 om 10:36 uur"), sep25.and_hms_opt(10, 36, 0).unwrap());
+    assert_eq!(parse_with(&mut nl, "3 mrt 2003"), NaiveDate::from_ymd_opt(2003, 3, 3).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+    assert!(ParserInfo::for_locale("xx").is_none());
+    assert_eq!(ParserInfo::for_locale("en-US"), Some(ParserInfo::default()));
+}
+
+#[cfg(feature = "cldr")]
+#[test]
+fn test_cldr_locales() {
+    let sep25 = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap();
+
+    let mut de = Parser::new(ParserInfo::from_cldr("de-CH").unwrap());
+    assert_eq!(parse_with(&mut de, "Do., 25. Sept. 2003 n. Chr."), sep25.and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut de, "25.09.03"), sep25.and_hms_opt(0, 0, 0).unwrap());
+
+    let mut es = Parser::new(ParserInfo::from_cldr("es").unwrap());
+    assert_eq!(parse_with(&mut es, "25 sept 2003 10:36 p. m."), sep25.and_hms_opt(22, 36, 0).unwrap());
+
+    let mut ja = Parser::new(ParserInfo::from_cldr("ja").unwrap());
+    assert_eq!(parse_with(&mut ja, "03/09/25"), sep25.and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut ja, "西暦2003 9月25"), sep25.and_hms_opt(0, 0, 0).unwrap());
+
+    // Common era names mark the year as Gregorian
+    let fr = ParserInfo::from_cldr("fr").unwrap();
+    let buddhist = ParserInfo::builder().merge(&fr).calendar(Calendar::Buddhist).build().unwrap();
+    let mut buddhist = Parser::new(buddhist);
+    assert_eq!(parse_with(&mut buddhist, "25 sept. 2546"), sep25.and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut buddhist, "25 sept. 2003 ap. J.-C."), sep25.and_hms_opt(0, 0, 0).unwrap());
+
+    assert!(ParserInfo::cldr_locales().contains(&"fr"));
+    assert!(ParserInfo::from_cldr("tlh").is_none());
+}
+
+#[test]
+fn test_parser_info_builder() {
+    let info = ParserInfo::builder()
+        .add(Vocabulary::RelativeDay, -2, &["ereyesterday"])
+        .add(Vocabulary::Month, 9, &["Setembro"])
+        .remove(Vocabulary::AmPm, &["a", "p"])
+        .tzoffset("BRT", -3 * 3600)
+        .dayfirst(true)
+        .reference_year(1950)
+        .build()
+        .unwrap();
+    let mut parser = Parser::new(info);
+
+    assert_eq!(parse_with(&mut parser, "ereyesterday"), NaiveDate::from_ymd_opt(2003, 9, 23).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "01/02/03"), NaiveDate::from_ymd_opt(1903, 2, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "25 Setembro 2003"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+    let default = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let (_, offset, _) = parser
+        .parse("10:36 BRT", None, None, false, false, Some(&default), false, HashMap::new())
+        .unwrap();
+    assert_eq!(offset.map(|o| o.local_minus_utc()), Some(-3 * 3600));
+}
+
+#[test]
+fn test_multiple_locales() {
+    let info = ParserInfo::for_locales(&["en", "de", "es"]).unwrap().unwrap();
+    let mut parser = Parser::new(info);
+
+    assert_eq!(parse_with(&mut parser, "May 25 2003"), NaiveDate::from_ymd_opt(2003, 5, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "25. Mai 2003"), NaiveDate::from_ymd_opt(2003, 5, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "25 de marzo de 2003"), NaiveDate::from_ymd_opt(2003, 3, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    // "am" is AM, and skipped as the German "am" (on); jump words don't conflict
+    assert_eq!(parse_with(&mut parser, "10:00 am"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(10, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "am 25. Mai 2003"), NaiveDate::from_ymd_opt(2003, 5, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+    let conflicts = ParserInfo::conflicts(&[ParserInfo::default(), ParserInfo::for_locale("de").unwrap()]);
+    assert!(!conflicts.iter().any(|c| c.word == "am"));
+    assert!(ParserInfo::for_locales(&["en", "tlh"]).is_none());
+
+    // English wins: "mar" is March rather than the French Tuesday
+    let mut parser = Parser::new(ParserInfo::for_locales(&["en", "fr"]).unwrap().unwrap());
+    assert_eq!(parse_with(&mut parser, "3 mar 2003"), NaiveDate::from_ymd_opt(2003, 3, 3).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "Mar 25 2003"), NaiveDate::from_ymd_opt(2003, 3, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut parser, "mardi 25 mars 2003"), NaiveDate::from_ymd_opt(2003, 3, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    let conflicts = ParserInfo::conflicts(&[ParserInfo::default(), ParserInfo::for_locale("fr").unwrap()]);
+    assert!(conflicts.iter().any(|c| c.word == "mar"));
+}
+
+#[test]
+fn test_unicode_digits() {
+    let expected = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(10, 36, 28).unwrap();
+
+    assert_eq!(parse("٢٠٠٣-٠٩-٢٥ ١٠:٣٦:٢٨").unwrap().0, expected);
+    assert_eq!(parse("२००३-०९-२५ १०:३६:२८").unwrap().0, expected);
+    assert_eq!(parse("２００３－０９－２５　１０：３６：２８").unwrap().0, expected);
+    assert_eq!(parse("Ｓｅｐ ２５ ２００３ １０：３６：２８.５").unwrap().0, expected.with_nanosecond(500_000_000).unwrap());
+    assert!(parse("²⁰⁰³").is_err());
+}
+
+#[test]
+fn test_cjk() {
+    let mut ja = Parser::new(ParserInfo::for_locale("ja").unwrap());
+    assert_eq!(parse_with(&mut ja, "2003年9月25日 10時36分"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(10, 36, 0).unwrap());
+    assert_eq!(parse_with(&mut ja, "２００３年９月２５日（木曜日）午後３時"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(15, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut ja, "03/09/25"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut ja, "明日"), NaiveDate::from_ymd_opt(2003, 9, 26).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+    let mut zh = Parser::new(ParserInfo::for_locale("zh-CN").unwrap());
+    assert_eq!(parse_with(&mut zh, "2003年09月25日 上午10点"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(10, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut zh, "2003年9月25日下午3点30分15秒"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(15, 30, 15).unwrap());
+    assert_eq!(parse_with(&mut zh, "9月25号 星期四 晚上8点"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(20, 0, 0).unwrap());
+}
+
+#[test]
+fn test_japanese_eras() {
+    let mut ja = Parser::new(ParserInfo::for_locale("ja").unwrap());
+    assert_eq!(parse_with(&mut ja, "令和5年9月25日"), NaiveDate::from_ymd_opt(2023, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut ja, "平成元年1月8日"), NaiveDate::from_ymd_opt(1989, 1, 8).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut ja, "平成15年"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+
+    let mut parser = Parser::default();
+    assert_eq!(parse_with(&mut parser, "Sep 25 Heisei 15"), NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    // Past the end of the era
+    assert!(parser.parse("Sep 25 Heisei 99", None, None, false, false, None, false, HashMap::new()).is_err());
+    assert!(parser.parse("Sep 25 Showa 70", None, None, false, false, None, false, HashMap::new()).is_err());
+
+    // Dates on either side of the change from Heisei to Reiwa
+    assert_eq!(parse_with(&mut ja, "平成31年4月30日"), NaiveDate::from_ymd_opt(2019, 4, 30).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    assert_eq!(parse_with(&mut ja, "令和元年5月1日"), NaiveDate::from_ymd_opt(2019, 5, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    for s in ["令和元年4月1日", "平成31年5月1日", "平成元年1月7日"].iter() {
+        let err = ja.parse(s, None, None, false, false, None, false, HashMap::new()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidYear, "{}", s);
+        assert_eq!(err.span(), 0..s.find('年').unwrap() + '年'.len_utf8(), "{}", s);
+    }
+}
+
+#[test]
+fn test_year_offset_calendars() {
+    let expected = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+    let mut parser = Parser::default();
+    assert_eq!(parse_with(&mut parser, "25 Sep 2546 BE"), expected);
+    assert_eq!(parse_with(&mut parser, "25 Sep พ.ศ. 2546"), expected);
+    assert_eq!(parse_with(&mut parser, "Sep 25 2003"), expected);
+
+    let mut thai = Parser::new(ParserInfo::builder().calendar(Calendar::Buddhist).dayfirst(true).build().unwrap());
+    assert_eq!(parse_with(&mut thai, "25/09/2546"), expected);
+
+    let mut zh = Parser::new(ParserInfo::for_locale("zh-TW").unwrap());
+    assert_eq!(parse_with(&mut zh, "民國92年9月25日"), expected);
+
+    let mut custom = Parser::new(ParserInfo::builder().calendar(Calendar::YearOffset(100)).build().unwrap());
+    assert_eq!(parse_with(&mut custom, "Sep 25 1903"), expected);
+
+    // Years that overflow once shifted are invalid rather than a panic
+    let invalid_year = |parser: &mut Parser, s: &str| {
+        let err = parser.parse_outcome(s, None, None, false, false, None, false, HashMap::new()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidYear, "{}", s);
+    };
+    invalid_year(&mut parser, "25 Sep 民國 2147483647");
+    invalid_year(&mut zh, "民國2147483647年9月25日");
+    let mut minguo = Parser::new(ParserInfo::builder().calendar(Calendar::Minguo).build().unwrap());
+    invalid_year(&mut minguo, "2147483647");
+    let mut max = Parser::new(ParserInfo::builder().calendar(Calendar::YearOffset(i32::MAX)).build().unwrap());
+    invalid_year(&mut max, "Sep 25 2003");
+}
+
+#[test]
+fn test_converted_calendars() {
+    let calendar = |c| Parser::new(ParserInfo::builder().calendar(c).build().unwrap());
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap().and_hms_opt(0, 0, 0).unwrap();
+
+    let mut persian = calendar(Calendar::Persian);
+    assert_eq!(parse_with(&mut persian, "25 Shahrivar 1382"), date(2003, 9, 16));
+    assert_eq!(parse_with(&mut persian, "31 Ordibehesht 1382"), date(2003, 5, 21));
+    assert_eq!(parse_with(&mut persian, "1382/07/03"), date(2003, 9, 25));
+    assert_eq!(parse_with(&mut persian, "Esfand 30 1403 10:00"), date(2025, 3, 20).with_hour(10).unwrap());
+
+    let mut hijri = calendar(Calendar::Hijri);
+    assert_eq!(parse_with(&mut hijri, "1 Ramadan 1424"), date(2003, 10, 27));
+    assert_eq!(parse_with(&mut hijri, "12 Rabi' al-Awwal 1424"), date(2003, 5, 14));
+    assert_eq!(parse_with(&mut hijri, "Ramadan"), date(2003, 11, 23));
+    assert!(hijri.parse("1 Ramadan 2000000000", None, None, false, false, None, false, HashMap::new()).is_err());
+
+    let mut hebrew = calendar(Calendar::Hebrew);
+    assert_eq!(parse_with(&mut hebrew, "28 Elul 5763"), date(2003, 9, 25));
+    assert_eq!(parse_with(&mut hebrew, "14 Adar II 5784"), date(2024, 3, 24));
+    assert!(hebrew.parse("30 Adar II 5784", None, None, false, false, None, false, HashMap::new()).is_ok());
+}
+
+#[test]
+fn test_warnings() {
+    let warnings = |s: &str| {
+        let default = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        Parser::default()
+            .parse_outcome(s, None, None, false, false, Some(&default), false, HashMap::new())
+            .expect(s)
+            .warnings
+    };
+    let defaulted = |components: &[Component]| -> Vec<Warning> {
+        components.iter().map(|&c| Warning::Defaulted(c)).collect()
+    };
+
+    assert_eq!(warnings("2003-09-25 10:36:28"), vec![]);
+    assert_eq!(warnings("Sep 2003"), defaulted(&[Component::Day, Component::Hour, Component::Minute, Component::Second]));
+    assert_eq!(warnings("tomorrow 10:36:28"), vec![]);
+
+    let mut expected = vec![Warning::DayClamped { from: 30, to: 28, span: 4..6 }];
+    expected.extend(defaulted(&[Component::Hour, Component::Minute, Component::Second]));
+    assert_eq!(warnings("Feb 30 2003"), expected);
+
+    assert_eq!(
+        warnings("Monday, 25/09/03 10:36:28 CEST"),
+        vec![
+            Warning::WeekdayIgnored { span: 0..6 },
+            Warning::YearPivoted { from: 3, to: 2003, span: 14..16, pivot: YearPivot::SlidingWindow },
+            Warning::UnknownTimezone { name: "CEST".to_owned(), span: 26..30 },
+        ]
+    );
+    assert_eq!(warnings("Thursday, 25/09/2003 10:36:28"), vec![]);
+}
+
+#[test]
+fn test_year_pivot() {
+    let parse_with = |pivot: YearPivot, s: &str| {
+        let info = ParserInfo::builder().reference_year(2018).year_pivot(pivot).build().unwrap();
+        let default = NaiveDate::from_ymd_opt(2018, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        Parser::new(info).parse_outcome(s, None, None, false, false, Some(&default), false, HashMap::new())
+    };
+    let year = |pivot: YearPivot, s: &str| parse_with(pivot, s).unwrap().datetime.year();
+
+    assert_eq!(year(YearPivot::SlidingWindow, "1/2/67"), 2067);
+    assert_eq!(year(YearPivot::SlidingWindow, "1/2/68"), 1968);
+    assert_eq!(year(YearPivot::FixedPivot(1930), "1/2/29"), 2029);
+    assert_eq!(year(YearPivot::FixedPivot(1930), "1/2/30"), 1930);
+    assert_eq!(year(YearPivot::PastOnly, "1/2/18"), 2018);
+    assert_eq!(year(YearPivot::PastOnly, "1/2/19"), 1919);
+    assert_eq!(year(YearPivot::FutureOnly, "1/2/17"), 2117);
+    assert_eq!(year(YearPivot::FutureOnly, "1/2/18"), 2018);
+
+    // Full years and other calendars are left alone
+    assert_eq!(year(YearPivot::Reject, "1/2/2019"), 2019);
+    let info = ParserInfo::builder().calendar(Calendar::Minguo).year_pivot(YearPivot::Reject).build().unwrap();
+    assert_eq!(Parser::new(info).parse("9/25/92", None, None, false, false, None, false, HashMap::new()).unwrap().0.year(), 2003);
+
+    let err = parse_with(YearPivot::Reject, "Sep 25 03").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidYear);
+    assert_eq!(err.span(), 7..9);
+
+    assert_eq!(
+        parse_with(YearPivot::PastOnly, "Sep 25 19 10:36:28").unwrap().warnings,
+        vec![Warning::YearPivoted { from: 19, to: 1919, span: 7..9, pivot: YearPivot::PastOnly }]
+    );
+}
+
+#[test]
+fn test_clock() {
+    let new_years_eve = NaiveDate::from_ymd_opt(2049, 12, 31).unwrap().and_hms_opt(23, 59, 59).unwrap();
+    let info = ParserInfo::builder().clock(FixedClock(new_years_eve)).build().unwrap();
+    assert!(info != ParserInfo::default());
+
+    let mut parser = Parser::new(info.clone());
+    let (dt, _, _) = parser.parse("Jan 1 98", None, None, false, false, None, false, HashMap::new()).unwrap();
+    assert_eq!(dt, NaiveDate::from_ymd_opt(2098, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    let (dt, _, _) = parser.parse("10:36", None, None, false, false, None, false, HashMap::new()).unwrap();
+    assert_eq!(dt, NaiveDate::from_ymd_opt(2049, 12, 31).unwrap().and_hms_opt(10, 36, 0).unwrap());
+
+    // An explicit reference year wins over the clock's
+    let info = ParserInfo::builder().clock(FixedClock(new_years_eve)).reference_year(2003).build().unwrap();
+    let (dt, _, _) = Parser::new(info).parse("Jan 1 98", None, None, false, false, None, false, HashMap::new()).unwrap();
+    assert_eq!(dt.year(), 1998);
+
+    // Equality goes by the reference year, not the clock that gave it
+    let new_years_day = new_years_eve + Duration::seconds(1);
+    let info = |clock| ParserInfo::builder().clock(clock).reference_year(2003).build().unwrap();
+    assert!(info(FixedClock(new_years_eve)) == info(FixedClock(new_years_day)));
+}
+
+#[test]
+fn test_iso_fast_path() {
+    let mut parser = Parser::default();
+    let mut times = vec![String::new()];
+    for separator in ["T", " "].iter() {
+        for time in ["00:00", "10:49", "23:59:59", "10:49:41", "10:49:41.5", "10:49:41.123456789"].iter() {
+            for offset in ["", "Z", "+00:00", "-00:00", "-03:00", "+0530", "+05", "-23:59"].iter() {
+                times.push(format!("{}{}{}", separator, time, offset));
+            }
+        }
+    }
+
+    for year in ["0000", "0031", "0099", "0100", "1999", "2003", "9999"].iter() {
+        for month in ["01", "02", "09", "12"].iter() {
+            for day in ["01", "12", "13", "29", "30", "31"].iter() {
+                for time in times.iter() {
+                    let s = format!("{}-{}-{}{}", year, month, day, time);
+                    assert!(::iso::parse_iso(&s).is_some(), "{}", s);
+                    assert_eq!(
+                        parser.parse_with_tokens(&s, None, None, false, false),
+                        parser.parse_heuristic(&s, false, false, false, false),
+                        "{}",
+                        s
+                    );
+                }
+            }
+        }
+    }
+
+    // dateutil swaps the month and day of ISO dates with dayfirst
+    let (dt, _, _) = parser.parse("2003-09-05", Some(true), None, false, false, None, false, HashMap::new()).unwrap();
+    assert_eq!(dt, NaiveDate::from_ymd_opt(2003, 5, 9).unwrap().and_hms_opt(0, 0, 0).unwrap());
+}
+
+#[test]
+fn test_shared_default_info() {
+    assert!(Arc::ptr_eq(&Parser::default().info, &Parser::default().info));
+
+    // Changes made after building still reach the lexicon
+    let mut info = ParserInfo::for_locale("fr").unwrap();
+    assert_eq!(info.get_weekday("MARDI"), Some(1));
+    info.set_named_time("goûter", 16).unwrap();
+    assert_eq!(info.get_named_time("Goûter"), Some(16));
+}
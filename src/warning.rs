@@ -0,0 +1,90 @@
+use std::fmt;
+use std::ops::Range;
+
+use YearPivot;
+
+/// A part of the result that can be missing from the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Component::Year => "year",
+            Component::Month => "month",
+            Component::Day => "day",
+            Component::Hour => "hour",
+            Component::Minute => "minute",
+            Component::Second => "second",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Something the parser guessed or dropped on its way to a result. Spans
+/// are byte ranges of the input, like `ParseError::span`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// A timezone name with no known offset. The result has no offset.
+    UnknownTimezone { name: String, span: Range<usize> },
+    /// A component missing from the input, taken from the default date, or
+    /// zero for times. Dates relative to the default ("tomorrow", "Monday",
+    /// "T+3") don't report their date components.
+    Defaulted(Component),
+    /// A two-digit year expanded to a full year by the `YearPivot` of the
+    /// `ParserInfo`.
+    YearPivoted {
+        from: i32,
+        to: i32,
+        span: Range<usize>,
+        pivot: YearPivot,
+    },
+    /// A weekday that doesn't fall on the explicit date it came with. The
+    /// date is kept.
+    WeekdayIgnored { span: Range<usize> },
+    /// A day past the end of its month, moved back to the last day.
+    DayClamped { from: u32, to: u32, span: Range<usize> },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Warning::UnknownTimezone { ref name, ref span } => write!(
+                f,
+                "unknown timezone {:?} at {}..{} ignored",
+                name, span.start, span.end
+            ),
+            Warning::Defaulted(component) => write!(f, "no {} given, using the default", component),
+            Warning::YearPivoted { from, to, ref span, .. } => {
+                write!(f, "two-digit year {} at {}..{} read as {}", from, span.start, span.end, to)
+            }
+            Warning::WeekdayIgnored { ref span } => write!(
+                f,
+                "weekday at {}..{} doesn't match the date and was ignored",
+                span.start, span.end
+            ),
+            Warning::DayClamped { from, to, ref span } => write!(
+                f,
+                "day {} at {}..{} is past the end of the month, using {}",
+                from, span.start, span.end, to
+            ),
+        }
+    }
+}
+
+/// Report `warning` through the `log` crate: timezones that were dropped as
+/// warnings, guesses the parser makes routinely at debug level.
+#[cfg(feature = "log")]
+pub fn log_warning(warning: &Warning) {
+    match *warning {
+        Warning::UnknownTimezone { .. } | Warning::WeekdayIgnored { .. } => warn!("{}", warning),
+        _ => debug!("{}", warning),
+    }
+}
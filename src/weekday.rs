@@ -1,9 +1,10 @@
 use std::cmp::max;
 
-use ParseResult;
-use ParseError;
+use ErrorKind;
+use ParseIResult;
+use days_in_month;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub enum DayOfWeek {
     Sunday,
     Monday,
@@ -55,18 +56,19 @@ impl DayOfWeek {
     }
 }
 
-pub fn day_of_week(year: u32, month: u32, day: u32) -> ParseResult<DayOfWeek> {
+pub fn day_of_week(year: u32, month: u32, day: u32) -> ParseIResult<DayOfWeek> {
     // From https://en.wikipedia.org/wiki/Determination_of_the_day_of_the_week#Schwerdtfeger's_method
     let (c, g) = match month {
-        3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 => {
+        3..=12 => {
             let c = year / 100;
             (c, year - 100 * c)
         },
-        1 | 2 => {
+        1 | 2 if year > 0 => {
             let c = (year - 1) / 100;
             (c, year - 1 - 100 * c)
         },
-        _ => return Err(ParseError::InvalidMonth)
+        1 | 2 => return Err(ErrorKind::InvalidYear),
+        _ => return Err(ErrorKind::InvalidMonth)
     };
 
     let e = match month {
@@ -101,9 +103,39 @@ pub fn day_of_week(year: u32, month: u32, day: u32) -> ParseResult<DayOfWeek> {
     }
 }
 
+/// Day of the month on which the `n`th `weekday` of a month falls. Negative
+/// values of `n` count back from the end of the month, so `-1` is the last
+/// such weekday. Returns `None` if the month has no such day.
+pub fn nth_weekday_of_month(year: i32, month: u32, weekday: DayOfWeek, n: i32) -> Option<u32> {
+    if year < 1 || n == 0 {
+        return None;
+    }
+
+    let last_day = days_in_month(year, month as i32).ok()?;
+
+    if n > 0 {
+        let first = day_of_week(year as u32, month, 1).ok()?;
+        let day = 1 + first.difference(weekday) + 7 * (n as u32 - 1).min(5);
+        if day <= last_day {
+            Some(day)
+        } else {
+            None
+        }
+    } else {
+        let last = day_of_week(year as u32, month, last_day).ok()?;
+        let back = weekday.difference(last) + 7 * (n.checked_neg()? as u32 - 1).min(5);
+        if back < last_day {
+            Some(last_day - back)
+        } else {
+            None
+        }
+    }
+}
+
 mod test {
 
     use weekday::day_of_week;
+    use weekday::nth_weekday_of_month;
     use weekday::DayOfWeek;
 
     #[test]
@@ -129,4 +161,17 @@ mod test {
         assert_eq!(DayOfWeek::Friday.difference(DayOfWeek::Sunday), 2);
         assert_eq!(DayOfWeek::Saturday.difference(DayOfWeek::Sunday), 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn nth_weekday_examples() {
+        // Thanksgiving 2003: fourth Thursday of November
+        assert_eq!(nth_weekday_of_month(2003, 11, DayOfWeek::Thursday, 4), Some(27));
+        // Memorial Day 2018: last Monday of May
+        assert_eq!(nth_weekday_of_month(2018, 5, DayOfWeek::Monday, -1), Some(28));
+        assert_eq!(nth_weekday_of_month(2003, 9, DayOfWeek::Monday, 1), Some(1));
+        assert_eq!(nth_weekday_of_month(2003, 9, DayOfWeek::Monday, 5), Some(29));
+        assert_eq!(nth_weekday_of_month(2003, 9, DayOfWeek::Tuesday, 5), Some(30));
+        assert_eq!(nth_weekday_of_month(2003, 9, DayOfWeek::Wednesday, 5), None);
+        assert_eq!(nth_weekday_of_month(2003, 9, DayOfWeek::Monday, 0), None);
+    }
+}
@@ -0,0 +1,54 @@
+// Replays the inputs saved under fuzz/regressions/<target>/ through the fuzz
+// target bodies, so inputs that once crashed a fuzz target keep being
+// checked by `cargo test`. See fuzz/README.md.
+
+
+extern crate chrono;
+extern crate dtparse;
+
+use std::fs;
+use std::panic;
+use std::path::Path;
+
+#[path = "../fuzz/harness.rs"]
+mod harness;
+
+fn replay(target: &str, run: fn(&[u8])) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/regressions").join(target);
+    let mut paths: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+
+    let failed: Vec<_> = paths
+        .iter()
+        .filter(|path| {
+            let data = fs::read(path).unwrap();
+            panic::catch_unwind(|| run(&data)).is_err()
+        })
+        .map(|path| path.display().to_string())
+        .collect();
+
+    assert!(failed.is_empty(), "{} panicked on {:?}", target, failed);
+}
+
+#[test]
+fn tokenize() {
+    replay("tokenize", harness::tokenize);
+}
+
+#[test]
+fn parse() {
+    replay("parse", harness::parse);
+}
+
+#[test]
+fn parser_options() {
+    replay("parser_options", harness::parser_options);
+}
+
+#[test]
+fn iso() {
+    replay("iso", harness::iso);
+}
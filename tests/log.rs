@@ -0,0 +1,46 @@
+#![cfg(feature = "log")]
+
+extern crate dtparse;
+extern crate log;
+
+use log::Level;
+use log::LevelFilter;
+use log::Log;
+use log::Metadata;
+use log::Record;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use dtparse::Parser;
+
+struct Collect(Mutex<Vec<(Level, String)>>);
+
+impl Log for Collect {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        if record.target().starts_with("dtparse") {
+            self.0.lock().unwrap().push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+#[test]
+fn warnings_are_logged() {
+    let logger: &'static Collect = Box::leak(Box::new(Collect(Mutex::new(Vec::new()))));
+    log::set_logger(logger).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    Parser::default()
+        .parse("Sep 25 2003 10:36:28 XYZ", None, None, false, false, None, false, HashMap::new())
+        .unwrap();
+
+    assert_eq!(
+        *logger.0.lock().unwrap(),
+        vec![(Level::Warn, "unknown timezone \"XYZ\" at 21..24 ignored".to_owned())]
+    );
+}
@@ -0,0 +1,170 @@
+
+extern crate chrono;
+extern crate dtparse;
+extern crate proptest;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use proptest::prelude::*;
+use std::collections::HashMap;
+
+use dtparse::tokenize;
+use dtparse::BusinessCalendar;
+use dtparse::Calendar;
+use dtparse::HolidaySet;
+use dtparse::Parser;
+use dtparse::ParserInfo;
+use dtparse::ParserInfoBuilder;
+
+/// Pieces of date strings, joined at random to reach the parser's less
+/// travelled branches.
+const FRAGMENTS: &[&str] = &[
+    "Jan", "Sep", "Monday", "of", "the", "at", "in", "last", "st", "th", "am", "pm", "noon",
+    "today", "half", "past", "quarter", "three", "twenty", "hundred", "business", "day",
+    "Christmas", "EST", "UTC", "GMT", "Z", "T", "BE", "民國", "平成", "元", "年", "月", "日",
+    "午後", "Adar", "II", "Ramadan", "h", "m", "s", "0", "1", "10", "12", "13", "24", "31", "60",
+    "99", "2003", "0000", "123456", "12345678", "2147483647", "2147483648", "3.5", "1.2.3", "99999999999999999999", "١", "２",
+    " ", "-", "+", ":", ".", ",", "/", "'", "(", ")", "T+", "--", "..",
+];
+
+/// Calendars the corpus is replayed under, including the year offsets
+/// that overflow the most easily.
+const CALENDARS: &[Calendar] = &[
+    Calendar::Gregorian,
+    Calendar::Minguo,
+    Calendar::Hijri,
+    Calendar::YearOffset(i32::MAX),
+    Calendar::YearOffset(i32::MIN),
+];
+
+const LOCALES: &[&str] = &["en", "fr", "ja", "zh-TW"];
+
+fn fragments() -> impl Strategy<Value = String> {
+    prop::collection::vec(prop::sample::select(FRAGMENTS), 1..10).prop_map(|parts| parts.concat())
+}
+
+fn parser(locale: usize, calendar: Calendar) -> Parser {
+    let tag = LOCALES[locale];
+    let info = ParserInfoBuilder::from(ParserInfo::for_locale(tag).unwrap())
+        .calendar(calendar)
+        .build()
+        .unwrap();
+
+    let mut parser = Parser::new(info);
+    parser.add_holiday_calendar(HolidaySet::us());
+    parser.set_business_calendar(BusinessCalendar::new());
+    parser
+}
+
+fn calendars() -> impl Strategy<Value = Calendar> {
+    prop_oneof![
+        Just(Calendar::Gregorian),
+        Just(Calendar::Buddhist),
+        Just(Calendar::Hebrew),
+        Just(Calendar::Persian),
+        Just(Calendar::Hijri),
+        Just(Calendar::Minguo),
+        Just(Calendar::YearOffset(i32::MAX)),
+        Just(Calendar::YearOffset(i32::MIN)),
+        any::<i32>().prop_map(Calendar::YearOffset),
+    ]
+}
+
+fn default() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap()
+}
+
+#[test]
+fn corpus() {
+    let corpus = include_str!("corpus/panics.txt");
+    let inputs = corpus.lines().filter(|l| !l.is_empty() && !l.starts_with('#'));
+
+    let mut parsers: Vec<Parser> = (0..LOCALES.len())
+        .flat_map(|locale| CALENDARS.iter().map(move |&calendar| parser(locale, calendar)))
+        .collect();
+
+    for input in inputs {
+        let _ = tokenize(input);
+        for parser in &mut parsers {
+            for &fuzzy in &[false, true] {
+                for &dayfirst in &[false, true] {
+                    let _ = parser.parse(
+                        input,
+                        Some(dayfirst),
+                        None,
+                        fuzzy,
+                        fuzzy,
+                        Some(&default()),
+                        false,
+                        HashMap::new(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn any_string(ref input in any::<String>()) {
+        let _ = tokenize(input);
+        let _ = dtparse::parse(input);
+    }
+
+    #[test]
+    fn date_fragments(
+        ref input in fragments(),
+        locale in 0..LOCALES.len(),
+        calendar in calendars(),
+        dayfirst in any::<bool>(),
+        yearfirst in any::<bool>(),
+        fuzzy in any::<bool>(),
+        fuzzy_with_tokens in any::<bool>(),
+    ) {
+        let _ = parser(locale, calendar).parse(
+            input,
+            Some(dayfirst),
+            Some(yearfirst),
+            fuzzy,
+            fuzzy_with_tokens,
+            Some(&default()),
+            false,
+            HashMap::new(),
+        );
+    }
+
+    #[test]
+    fn business_day_offsets(
+        ref offsets in prop::collection::vec(
+            (prop_oneof![Just("T+"), Just("T-")], prop_oneof![Just(i64::MAX), 0..=i64::MAX, 0..100_000_000i64]),
+            1..4,
+        ),
+        with_holidays in any::<bool>(),
+    ) {
+        let input: Vec<String> = offsets.iter().map(|&(sign, days)| format!("{}{}", sign, days)).collect();
+        let mut calendar = BusinessCalendar::new();
+        if with_holidays {
+            calendar.add_holidays(&HolidaySet::us(), 2000, 2010);
+        }
+
+        let mut parser = Parser::default();
+        parser.set_business_calendar(calendar);
+        let _ = parser.parse(&input.join(" "), None, None, false, false, Some(&default()), false, HashMap::new());
+    }
+
+    #[test]
+    fn formatted_dates_round_trip(
+        days in 0..146097i64,
+        seconds in 0..86400u32,
+    ) {
+        let expected = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap()
+            + chrono::Duration::days(days)
+            + chrono::Duration::seconds(seconds as i64);
+
+        for format in &["%Y-%m-%d %H:%M:%S", "%d %B %Y %H:%M:%S", "%a, %b %d %Y %I:%M:%S %p"] {
+            let input = expected.format(format).to_string();
+            let (parsed, _) = dtparse::parse(&input).unwrap();
+            prop_assert_eq!(parsed, expected, "{}", input);
+        }
+    }
+}
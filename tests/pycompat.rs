@@ -23,6 +23,7 @@ struct PyDateTime {
     tzo: Option<i32>
 }
 
+#[allow(clippy::too_many_arguments)]
 fn parse_and_assert(
     pdt: PyDateTime,
     info: ParserInfo,
@@ -45,7 +46,7 @@ fn parse_and_assert(
         fuzzy_with_tokens,
         default,
         ignoretz,
-        tzinfos).expect(&format!("Unable to parse date in Rust '{}'", s));
+        tzinfos).unwrap_or_else(|_| panic!("Unable to parse date in Rust '{}'", s));
 
     assert_eq!(pdt.year, rs_parsed.0.year(), "Year mismatch for '{}'", s);
     assert_eq!(pdt.month, rs_parsed.0.month(), "Month mismatch for '{}'", s);
@@ -53,7 +54,7 @@ fn parse_and_assert(
     assert_eq!(pdt.hour, rs_parsed.0.hour(), "Hour mismatch for '{}'", s);
     assert_eq!(pdt.minute, rs_parsed.0.minute(), "Minute mismatch f'or' {}", s);
     assert_eq!(pdt.second, rs_parsed.0.second(), "Second mismatch for '{}'", s);
-    assert_eq!(pdt.micros, rs_parsed.0.timestamp_subsec_micros(), "Microsecond mismatch for {}", s);
+    assert_eq!(pdt.micros, rs_parsed.0.nanosecond() / 1000, "Microsecond mismatch for {}", s);
     assert_eq!(pdt.tzo, rs_parsed.1.map(|u| u.local_minus_utc()), "Timezone Offset mismatch for {}", s);
 }
 
@@ -61,14 +62,14 @@ fn parse_and_assert_simple(
     pdt: PyDateTime,
     s: &str,
 ) {
-    let rs_parsed = dtparse::parse(s).expect(&format!("Unable to parse date in Rust '{}'", s));
+    let rs_parsed = dtparse::parse(s).unwrap_or_else(|_| panic!("Unable to parse date in Rust '{}'", s));
     assert_eq!(pdt.year, rs_parsed.0.year(), "Year mismatch for {}", s);
     assert_eq!(pdt.month, rs_parsed.0.month(), "Month mismatch for {}", s);
     assert_eq!(pdt.day, rs_parsed.0.day(), "Day mismatch for {}", s);
     assert_eq!(pdt.hour, rs_parsed.0.hour(), "Hour mismatch for {}", s);
     assert_eq!(pdt.minute, rs_parsed.0.minute(), "Minute mismatch for {}", s);
     assert_eq!(pdt.second, rs_parsed.0.second(), "Second mismatch for {}", s);
-    assert_eq!(pdt.micros, rs_parsed.0.timestamp_subsec_micros(), "Microsecond mismatch for {}", s);
+    assert_eq!(pdt.micros, rs_parsed.0.nanosecond() / 1000, "Microsecond mismatch for {}", s);
     assert_eq!(pdt.tzo, rs_parsed.1.map(|u| u.local_minus_utc()), "Timezone Offset mismatch for {}", s);
 }
 
@@ -83,7 +84,7 @@ macro_rules! rs_tzinfo_map {
 #[test]
 fn test_parse_default0() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 28,
@@ -96,7 +97,7 @@ fn test_parse_default0() {
 #[test]
 fn test_parse_default1() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 28,
@@ -109,7 +110,7 @@ fn test_parse_default1() {
 #[test]
 fn test_parse_default2() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 28,
@@ -122,7 +123,7 @@ fn test_parse_default2() {
 #[test]
 fn test_parse_default3() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 0,
@@ -135,7 +136,7 @@ fn test_parse_default3() {
 #[test]
 fn test_parse_default4() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 0, second: 0,
@@ -148,7 +149,7 @@ fn test_parse_default4() {
 #[test]
 fn test_parse_default5() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 0, second: 0,
@@ -161,7 +162,7 @@ fn test_parse_default5() {
 #[test]
 fn test_parse_default6() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 0, second: 0,
@@ -174,7 +175,7 @@ fn test_parse_default6() {
 #[test]
 fn test_parse_default7() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 28,
@@ -187,7 +188,7 @@ fn test_parse_default7() {
 #[test]
 fn test_parse_default8() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 28,
@@ -200,7 +201,7 @@ fn test_parse_default8() {
 #[test]
 fn test_parse_default9() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 0,
@@ -213,7 +214,7 @@ fn test_parse_default9() {
 #[test]
 fn test_parse_default10() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 0, second: 0,
@@ -226,7 +227,7 @@ fn test_parse_default10() {
 #[test]
 fn test_parse_default11() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 0,
@@ -239,7 +240,7 @@ fn test_parse_default11() {
 #[test]
 fn test_parse_default12() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 30,
@@ -252,7 +253,7 @@ fn test_parse_default12() {
 #[test]
 fn test_parse_default13() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 36, second: 5,
@@ -265,7 +266,7 @@ fn test_parse_default13() {
 #[test]
 fn test_parse_default14() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 36, second: 5,
@@ -278,7 +279,7 @@ fn test_parse_default14() {
 #[test]
 fn test_parse_default15() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 36, second: 5,
@@ -291,7 +292,7 @@ fn test_parse_default15() {
 #[test]
 fn test_parse_default16() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 36, second: 5,
@@ -304,7 +305,7 @@ fn test_parse_default16() {
 #[test]
 fn test_parse_default17() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 0, second: 0,
@@ -317,7 +318,7 @@ fn test_parse_default17() {
 #[test]
 fn test_parse_default18() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 22, minute: 0, second: 0,
@@ -330,7 +331,7 @@ fn test_parse_default18() {
 #[test]
 fn test_parse_default19() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 0, second: 0,
@@ -343,7 +344,7 @@ fn test_parse_default19() {
 #[test]
 fn test_parse_default20() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 22, minute: 0, second: 0,
@@ -356,7 +357,7 @@ fn test_parse_default20() {
 #[test]
 fn test_parse_default21() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 0, second: 0,
@@ -369,7 +370,7 @@ fn test_parse_default21() {
 #[test]
 fn test_parse_default22() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 22, minute: 0, second: 0,
@@ -382,7 +383,7 @@ fn test_parse_default22() {
 #[test]
 fn test_parse_default23() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 0, second: 0,
@@ -395,7 +396,7 @@ fn test_parse_default23() {
 #[test]
 fn test_parse_default24() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 22, minute: 0, second: 0,
@@ -408,7 +409,7 @@ fn test_parse_default24() {
 #[test]
 fn test_parse_default25() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 0, second: 0,
@@ -421,7 +422,7 @@ fn test_parse_default25() {
 #[test]
 fn test_parse_default26() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 22, minute: 0, second: 0,
@@ -434,7 +435,7 @@ fn test_parse_default26() {
 #[test]
 fn test_parse_default27() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 0, second: 0,
@@ -447,7 +448,7 @@ fn test_parse_default27() {
 #[test]
 fn test_parse_default28() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 22, minute: 0, second: 0,
@@ -460,7 +461,7 @@ fn test_parse_default28() {
 #[test]
 fn test_parse_default29() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 10, day: 25,
         hour: 0, minute: 0, second: 0,
@@ -473,7 +474,7 @@ fn test_parse_default29() {
 #[test]
 fn test_parse_default30() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2000, month: 12, day: 31,
         hour: 0, minute: 0, second: 0,
@@ -486,7 +487,7 @@ fn test_parse_default30() {
 #[test]
 fn test_parse_default31() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 1, second: 2,
@@ -499,7 +500,7 @@ fn test_parse_default31() {
 #[test]
 fn test_parse_default32() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 1, second: 2,
@@ -512,7 +513,7 @@ fn test_parse_default32() {
 #[test]
 fn test_parse_default33() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 12, minute: 8, second: 0,
@@ -525,7 +526,7 @@ fn test_parse_default33() {
 #[test]
 fn test_parse_default34() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 1, minute: 2, second: 3,
@@ -538,7 +539,7 @@ fn test_parse_default34() {
 #[test]
 fn test_parse_default35() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 1, minute: 2, second: 0,
@@ -551,7 +552,7 @@ fn test_parse_default35() {
 #[test]
 fn test_parse_default36() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 1, minute: 0, second: 2,
@@ -564,7 +565,7 @@ fn test_parse_default36() {
 #[test]
 fn test_parse_default37() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 1, second: 2,
@@ -577,7 +578,7 @@ fn test_parse_default37() {
 #[test]
 fn test_parse_default38() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 2, minute: 1, second: 0,
@@ -590,7 +591,7 @@ fn test_parse_default38() {
 #[test]
 fn test_parse_default39() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2004, month: 4, day: 10,
         hour: 11, minute: 30, second: 0,
@@ -603,7 +604,7 @@ fn test_parse_default39() {
 #[test]
 fn test_parse_default40() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 3,
         hour: 0, minute: 0, second: 0,
@@ -616,7 +617,7 @@ fn test_parse_default40() {
 #[test]
 fn test_parse_default41() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 0, minute: 0, second: 0,
@@ -629,7 +630,7 @@ fn test_parse_default41() {
 #[test]
 fn test_parse_default42() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2017, month: 11, day: 25,
         hour: 2, minute: 17, second: 0,
@@ -642,7 +643,7 @@ fn test_parse_default42() {
 #[test]
 fn test_parse_default43() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 28,
@@ -655,7 +656,7 @@ fn test_parse_default43() {
 #[test]
 fn test_parse_default44() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 9, day: 25,
         hour: 10, minute: 36, second: 28,
@@ -668,7 +669,7 @@ fn test_parse_default44() {
 #[test]
 fn test_parse_default45() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 10, day: 1,
         hour: 0, minute: 0, second: 0,
@@ -681,7 +682,7 @@ fn test_parse_default45() {
 #[test]
 fn test_parse_default46() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2003, 9, 25).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2003, month: 10, day: 1,
         hour: 0, minute: 0, second: 0,
@@ -1566,7 +1567,7 @@ fn test_parse_dfyf1() {
 #[test]
 fn test_unspecified_fallback0() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2010, 1, 31).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2010, 1, 31).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2009, month: 4, day: 30,
         hour: 0, minute: 0, second: 0,
@@ -1579,7 +1580,7 @@ fn test_unspecified_fallback0() {
 #[test]
 fn test_unspecified_fallback1() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2010, 1, 31).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2010, 1, 31).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2007, month: 2, day: 28,
         hour: 0, minute: 0, second: 0,
@@ -1592,7 +1593,7 @@ fn test_unspecified_fallback1() {
 #[test]
 fn test_unspecified_fallback2() {
     let info = ParserInfo::default();
-    let default_rsdate = &NaiveDate::from_ymd(2010, 1, 31).and_hms(0, 0, 0);
+    let default_rsdate = &NaiveDate::from_ymd_opt(2010, 1, 31).unwrap().and_hms_opt(0, 0, 0).unwrap();
     let pdt = PyDateTime {
         year: 2008, month: 2, day: 29,
         hour: 0, minute: 0, second: 0,
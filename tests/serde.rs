@@ -0,0 +1,93 @@
+#![cfg(feature = "serde")]
+
+extern crate chrono;
+extern crate dtparse;
+extern crate serde_json;
+extern crate toml;
+
+use chrono::Datelike;
+use chrono::NaiveDate;
+use std::collections::HashMap;
+
+use dtparse::Calendar;
+use dtparse::FixedClock;
+use dtparse::Parser;
+use dtparse::ParserInfo;
+use dtparse::Vocabulary;
+use dtparse::YearPivot;
+
+#[test]
+fn json_round_trip() {
+    let info = ParserInfo::builder()
+        .add(Vocabulary::RelativeDay, -2, &["ereyesterday"])
+        .tzoffset("EST", -5 * 3600)
+        .era("A.D.", Calendar::Gregorian)
+        .dayfirst(true)
+        .calendar(Calendar::YearOffset(100))
+        .year_pivot(YearPivot::FixedPivot(1930))
+        .build()
+        .unwrap();
+
+    let json = serde_json::to_string(&info).unwrap();
+    assert_eq!(serde_json::from_str::<ParserInfo>(&json).unwrap(), info);
+}
+
+#[test]
+fn toml_round_trip() {
+    for tag in &["en", "de", "es", "fr", "it", "ja", "nl", "pt", "zh"] {
+        let info = ParserInfo::for_locale(tag).unwrap();
+        let text = toml::to_string(&info).unwrap();
+        assert_eq!(toml::from_str::<ParserInfo>(&text).unwrap(), info, "{}", tag);
+    }
+}
+
+#[test]
+fn reference_year_round_trip() {
+    let new_years_eve = NaiveDate::from_ymd_opt(2049, 12, 31).unwrap().and_hms_opt(23, 59, 59).unwrap();
+    let load = |info: &ParserInfo| toml::from_str::<ParserInfo>(&toml::to_string(info).unwrap()).unwrap();
+    let expand = |info: ParserInfo| {
+        let (dt, _, _) = Parser::new(info).parse("Jan 1 98", None, None, false, false, None, false, HashMap::new()).unwrap();
+        dt.year()
+    };
+
+    // A year from the clock isn't saved, so the loaded info takes the
+    // current year from its own clock
+    let info = ParserInfo::builder().clock(FixedClock(new_years_eve)).build().unwrap();
+    assert!(!toml::to_string(&info).unwrap().contains("year ="));
+    assert_eq!(expand(load(&info)), expand(ParserInfo::default()));
+    assert!(load(&ParserInfo::default()) == ParserInfo::default());
+
+    let info = ParserInfo::builder().reference_year(2049).build().unwrap();
+    assert!(load(&info) == info);
+    assert_eq!(expand(load(&info)), 2098);
+}
+
+#[test]
+fn partial_toml() {
+    let info: ParserInfo = toml::from_str(
+        r#"
+        dayfirst = true
+        months = [
+            ["jan"], ["feb"], ["mar"], ["apr"], ["mai"], ["jun"],
+            ["jul"], ["aug"], ["sep", "sept", "setembro"], ["okt"], ["nov"], ["dez"],
+        ]
+
+        [tzoffset]
+        BRT = -10800
+        "#,
+    ).unwrap();
+
+    let default = NaiveDate::from_ymd_opt(2003, 9, 25).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let (dt, offset, _) = Parser::new(info)
+        .parse("01 Setembro 2003 10:36 BRT", None, None, false, false, Some(&default), false, HashMap::new())
+        .unwrap();
+    assert_eq!(dt, NaiveDate::from_ymd_opt(2003, 9, 1).unwrap().and_hms_opt(10, 36, 0).unwrap());
+    assert_eq!(offset.map(|o| o.local_minus_utc()), Some(-10800));
+}
+
+#[test]
+fn invalid_schema() {
+    assert!(toml::from_str::<ParserInfo>("months = [[\"jan\"]]").is_err());
+    assert!(toml::from_str::<ParserInfo>("[named_time]\nnoon = 25").is_err());
+    assert!(toml::from_str::<ParserInfo>("mnoths = []").is_err());
+}